@@ -10,9 +10,9 @@ extern crate cogeors;
 
 use cogeors::algorithms::convex_hull::*;
 use cogeors::Point2D;
+use rand::{RngExt, SeedableRng, rngs::StdRng};
 
 fn criterion_benchmark(c: &mut Criterion) {
-   use rand::{Rng, SeedableRng, StdRng};
 
     let mut rng: StdRng = SeedableRng::seed_from_u64((12.34f64).to_bits());
 
@@ -20,16 +20,52 @@ fn criterion_benchmark(c: &mut Criterion) {
     jm_points.push(Point2D::new(0.5,0.5));
 
     for _ in 0..10000{
-        jm_points.push( Point2D::new(rng.gen(),rng.gen()));
+        jm_points.push( Point2D::new(rng.random(),rng.random()));
     }
 
-    let mut mt_points = jm_points.clone();
-    let mut gs_points = jm_points.clone();
+    let mt_points = jm_points.clone();
+    let gs_points = jm_points.clone();
+    let qh_points = jm_points.clone();
+    let ch_points = jm_points.clone();
 
-    c.bench_function("jarvis march", move |b| b.iter(|| jarvis_march(&mut jm_points.clone())));
+    c.bench_function("jarvis march", move |b| b.iter(|| jarvis_march(&jm_points.clone())));
     c.bench_function("monotone chain", move |b| b.iter(|| monotone_chain(&mut mt_points.clone())));
     c.bench_function("graham scan", move |b| b.iter(|| graham_scan(&mut gs_points.clone())));
+    c.bench_function("quickhull", move |b| b.iter(|| quickhull(&qh_points.clone())));
+    c.bench_function("chan", move |b| b.iter(|| chan(&ch_points.clone())));
 }
 
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
\ No newline at end of file
+//10000 points with exactly 'hull_size' of them on a circle around the rest.
+fn points_with_hull_size(rng: &mut StdRng, hull_size: usize) -> Vec<Point2D<f64>>{
+    let mut points = Vec::new();
+    for i in 0..hull_size{
+        let angle = (i as f64) * std::f64::consts::PI * 2.0 / (hull_size as f64);
+        points.push(Point2D::new(angle.cos(),angle.sin()));
+    }
+    let inner_radius = (std::f64::consts::PI / (hull_size as f64)).cos() * 0.5;
+    while points.len() < 10000{
+        points.push(Point2D::new(rng.random::<f64>() * inner_radius,rng.random::<f64>() * inner_radius));
+    }
+    points
+}
+
+fn hull_size_benchmark(c: &mut Criterion) {
+    let mut rng: StdRng = SeedableRng::seed_from_u64((56.78f64).to_bits());
+
+    for &hull_size in &[8,64,512]{
+        let jm_points = points_with_hull_size(&mut rng,hull_size);
+        let mt_points = jm_points.clone();
+        let gs_points = jm_points.clone();
+        let qh_points = jm_points.clone();
+        let ch_points = jm_points.clone();
+
+        c.bench_function(&format!("jarvis march, hull size {}",hull_size), move |b| b.iter(|| jarvis_march(&jm_points.clone())));
+        c.bench_function(&format!("monotone chain, hull size {}",hull_size), move |b| b.iter(|| monotone_chain(&mut mt_points.clone())));
+        c.bench_function(&format!("graham scan, hull size {}",hull_size), move |b| b.iter(|| graham_scan(&mut gs_points.clone())));
+        c.bench_function(&format!("quickhull, hull size {}",hull_size), move |b| b.iter(|| quickhull(&qh_points.clone())));
+        c.bench_function(&format!("chan, hull size {}",hull_size), move |b| b.iter(|| chan(&ch_points.clone())));
+    }
+}
+
+criterion_group!(benches, criterion_benchmark, hull_size_benchmark);
+criterion_main!(benches);
@@ -1,39 +1,67 @@
-//!Algorithms for creating convex hulls.
-// from a set of Point2Ds.
-//Results starts from the left most point and goes clockwise.
+//!Algorithms for creating convex hulls from a set of points.
+//!
+//!Hulls start from the left most, then lowest, point and go clockwise unless HullOptions ask otherwise.
+//!The plain functions panic on empty input and on NaN coordinates, the try_ functions return a GeometryError instead.
+//!Every algorithm returns the same hull for the same points and options, and the hull of
+//! - one point, or only repeated copies of it, is that point.
+//! - two points, or points that are all on a line, is the two end points, or every point on the line
+//!   from the left most to the right most one when collinear points are included.
+//! - repeated points holds each hull vertex once.
 
-use crate::primatives2d::{Point2D,TurnDirection};
-use num_traits::Float;
+use crate::primatives2d::TurnDirection;
+use crate::coordinate::Coordinate;
+use crate::point2d_like::Point2DLike;
+use crate::polygon2d::Orientation;
+use crate::error::{GeometryError,check_points};
+use std::cmp::Ordering;
 
 
+///Options for the convex hull algorithms.
+///Every algorithm gives the same output for the same options.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HullOptions{
+	///Keep the points lying on the edges between the corners of the hull.
+	pub include_collinear : bool,
+	///Direction to go around the hull in, starting from the left most point.
+	///A hull of points on a line always goes from the left most to the right most point.
+	pub orientation : Orientation,
+}
+
+impl Default for HullOptions{
+	fn default() -> Self{
+		HullOptions{include_collinear: false,orientation: Orientation::Clockwise}
+	}
+}
+
 ///Preforms the Jarvis March/Gift Wrapping Algorithm on a set of points.
-pub fn jarvis_march<T>(points : &[Point2D<T>]) -> Vec<Point2D<T>> where T: Float{
+pub fn jarvis_march<P>(points : &[P]) -> Vec<P> where P: Point2DLike + Clone{
 	//https://en.wikipedia.org/wiki/Gift_wrapping_algorithm
 	
 
 	//find left most point
-	let left_lowest_most_point = *points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap();
+	let left_lowest_most_point = points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap().clone();
 
 
 	let mut hull = Vec::new();
-	hull.push(left_lowest_most_point);
+	hull.push(left_lowest_most_point.clone());
 	
-	let mut current_point = left_lowest_most_point;
+	let mut current_point = left_lowest_most_point.clone();
 	
 	loop{
-		let mut next_point =points[0];
-		for &p in points.iter(){
-			if (next_point == current_point) || ( current_point.turn_direction(&next_point,&p) == TurnDirection::LeftTurn)
+		let mut next_point = &points[0];
+		for p in points.iter(){
+			if wraps_further(&current_point,next_point,p)
 			{
 				next_point = p;
 			}
 		}
-		if next_point == left_lowest_most_point{
+		if same_position(next_point,&left_lowest_most_point){
 			break;
 		}
 		else{
-			hull.push(next_point);
-			current_point = next_point;
+			hull.push(next_point.clone());
+			current_point = next_point.clone();
 		}
 		
 		
@@ -43,39 +71,42 @@ pub fn jarvis_march<T>(points : &[Point2D<T>]) -> Vec<Point2D<T>> where T: Float
 }
 
 ///Preforms the Monotone Chain Algorithm on a set of points.
-pub fn monotone_chain<T>(points :&mut  Vec<Point2D<T>>) -> Vec<Point2D<T>> where T: Float{
+pub fn monotone_chain<P>(points :&mut [P]) -> Vec<P> where P: Point2DLike + Clone{
 	//https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain
 	
 	points.sort_by(|a,b| a.x_then_y_partial_cmp(b).unwrap());
+	if same_position(&points[0],&points[points.len()-1]){
+		return vec!(points[0].clone());
+	}
 	
-	let mut stack : Vec<Point2D<T>>= Vec::new();
+	let mut stack : Vec<P>= Vec::new();
 	
 	for p in points.iter(){
 
 		while 
 			stack.len() >= 2 &&
-			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],p) == TurnDirection::RightTurn)
+			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],p) != TurnDirection::LeftTurn)
 		{ 
 			stack.pop();
 		}
 		
-		stack.push(*p);
+		stack.push(p.clone());
 		
 	}
 	stack.pop();
 	
 	let last_len = stack.len();
 	
-	for &p in points.iter().rev(){
+	for p in points.iter().rev(){
 		while 
 		
 			stack.len() >= last_len+2 &&
-			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],&p) == TurnDirection::RightTurn)
+			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],p) != TurnDirection::LeftTurn)
 		{ 
 			stack.pop();
 		}
 		
-		stack.push(p);
+		stack.push(p.clone());
 		
 	}
 	
@@ -85,38 +116,349 @@ pub fn monotone_chain<T>(points :&mut  Vec<Point2D<T>>) -> Vec<Point2D<T>> where
 }
 
 ///Preforms the Graham Scan Algorithm on a set of points.
-pub fn graham_scan<T>(points :&mut  Vec<Point2D<T>>) -> Vec<Point2D<T>> where T: Float{
+pub fn graham_scan<P>(points :&mut [P]) -> Vec<P> where P: Point2DLike + Clone{
 	//https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain
 	
 	//find left most point
-	let left_lowest_most_point = *points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap();
+	let left_lowest_most_point = points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap().clone();
 	
-	points.sort_by(|a,b| a.rotation_point_cmp(&b,&left_lowest_most_point).unwrap());
+	//Every other point is right of the pivot or straight above it, all in the half plane left of straight down,
+	//so the exact turn test alone orders their directions. Sorting by decreasing angle measured from
+	//straight down visits them clockwise. Points in the same direction go nearest first.
+	let pivot = &left_lowest_most_point;
+	points.sort_by(|a,b| {
+		(!same_position(a,pivot)).cmp(&!same_position(b,pivot))
+			.then_with(|| match pivot.turn_direction(a,b){
+				TurnDirection::LeftTurn  => Ordering::Greater,
+				TurnDirection::RightTurn => Ordering::Less,
+				TurnDirection::NoTurn    => Ordering::Equal,
+			})
+			.then_with(|| a.x_then_y_partial_cmp(b).unwrap())
+	});
 	
-	let mut stack : Vec<Point2D<T>>= Vec::new();
+	let mut stack : Vec<P>= Vec::new();
 	
-	for &p in points.iter(){
+	for p in points.iter(){
 
 		while 
 			stack.len() >= 2 &&
-			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],&p) == TurnDirection::RightTurn)
+			(stack[stack.len()-1].turn_direction(&stack[stack.len()-2],p) != TurnDirection::LeftTurn)
 		{ 
 			stack.pop();
 		}
 		
-		stack.push(p);
+		stack.push(p.clone());
 		
 	}
 
+	//points in the last direction came nearest first, all but the furthest are on the closing edge
+	while stack.len() >= 3 && left_lowest_most_point.turn_direction(&stack[stack.len()-2],&stack[stack.len()-1]) == TurnDirection::NoTurn{
+		stack.remove(stack.len()-2);
+	}
+	if stack.len() == 2 && same_position(&stack[0],&stack[1]){
+		stack.pop();
+	}
 	
 	stack
 }
 
 
 
+///Preforms the Quickhull Algorithm on a set of points.
+pub fn quickhull<P>(points : &[P]) -> Vec<P> where P: Point2DLike + Clone{
+	//https://en.wikipedia.org/wiki/Quickhull
+
+	//find left most and right most points
+	let left  = points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap();
+	let right = points.iter().max_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap();
+
+	let mut hull = vec!(left.clone());
+	if same_position(left,right){
+		return hull;
+	}
+
+	let above : Vec<&P> = points.iter().filter(|p| left.turn_direction(right,p) == TurnDirection::LeftTurn).collect();
+	let below : Vec<&P> = points.iter().filter(|p| right.turn_direction(left,p) == TurnDirection::LeftTurn).collect();
+
+	//clockwise from the left most point is over the top first
+	quickhull_side(left,right,&above,&mut hull);
+	hull.push(right.clone());
+	quickhull_side(right,left,&below,&mut hull);
+
+	hull
+}
+
+//Pushes the hull points between a and b in order, all of 'points' are left of a->b.
+fn quickhull_side<P>(a: &P, b: &P, points: &[&P], hull: &mut Vec<P>) where P: Point2DLike + Clone{
+	//the point furthest from a->b is on the hull, of equally far points the one nearest a is a corner
+	let furthest = match points.iter().max_by(|p,q| distance_cmp(a,b,p,q).then_with(|| along_cmp(a,b,q,p))){
+		Some(&point) => point,
+		None => return,
+	};
+
+	let before : Vec<&P> = points.iter().cloned().filter(|p| a.turn_direction(furthest,p) == TurnDirection::LeftTurn).collect();
+	let after  : Vec<&P> = points.iter().cloned().filter(|p| furthest.turn_direction(b,p) == TurnDirection::LeftTurn).collect();
+
+	quickhull_side(a,furthest,&before,hull);
+	hull.push(furthest.clone());
+	quickhull_side(furthest,b,&after,hull);
+}
+
+//Compares how far p and q are left of the line through a and b, exactly.
+//That is the sign of the cross product of a->b and q->p.
+fn distance_cmp<P: Point2DLike>(a: &P, b: &P, p: &P, q: &P) -> Ordering{
+	P::Coordinate::cross_sign(&a.x(),&a.y(),&b.x(),&b.y(),&q.x(),&q.y(),&p.x(),&p.y())
+}
+
+//Compares how far along a->b p and q lie, exactly.
+//That is the sign of the dot product of a->b and q->p, written as the cross product of a->b and
+//(p.y,q.x)->(q.y,p.x), which is q->p turned a quarter turn counter-clockwise without negating a coordinate.
+fn along_cmp<P: Point2DLike>(a: &P, b: &P, p: &P, q: &P) -> Ordering{
+	P::Coordinate::cross_sign(&a.x(),&a.y(),&b.x(),&b.y(),&p.y(),&q.x(),&q.y(),&p.x())
+}
+
+///Preforms Chan's Algorithm on a set of points.
+///Output sensitive, it takes O(n log h) time for a hull of h points.
+pub fn chan<P>(points : &[P]) -> Vec<P> where P: Point2DLike + Clone{
+	//https://en.wikipedia.org/wiki/Chan%27s_algorithm
+
+	//find left most point
+	let left_lowest_most_point = points.iter().min_by(|&a,&b| a.x_then_y_partial_cmp(b).unwrap()).unwrap();
+
+	//guess the hull size, squaring the guess every time the wrapping does not close
+	let mut group_size : usize = 4;
+	loop{
+		let mini_hulls : Vec<Vec<P>> = points.chunks(group_size).map(mini_hull).collect();
+
+		let mut hull = vec!(left_lowest_most_point.clone());
+		let mut current_point = left_lowest_most_point;
+
+		for _ in 0..group_size{
+			let mut next_point = tangent(current_point,&mini_hulls[0]);
+			for mini_hull in mini_hulls.iter().skip(1){
+				let candidate = tangent(current_point,mini_hull);
+				if wraps_further(current_point,next_point,candidate){
+					next_point = candidate;
+				}
+			}
+
+			if same_position(next_point,left_lowest_most_point){
+				return hull;
+			}
+			hull.push(next_point.clone());
+			current_point = next_point;
+		}
+
+		group_size = group_size.saturating_mul(group_size);
+	}
+}
+
+//Clockwise hull of the points without collinear or repeated vertices, for Chan's Algorithm.
+//Points that are all collinear give the two end points.
+fn mini_hull<P>(points: &[P]) -> Vec<P> where P: Point2DLike + Clone{
+	//monotone_chain needs the repeated points removed
+	let mut points = points.to_vec();
+	points.sort_by(|a,b| a.x_then_y_partial_cmp(b).unwrap());
+	points.dedup_by(|a,b| same_position(a,b));
+	let hull = monotone_chain(&mut points);
+
+	let len = hull.len();
+	let corners : Vec<P> = (0..len)
+		.filter(|&i| hull[(i+len-1)%len].turn_direction(&hull[i],&hull[(i+1)%len]) == TurnDirection::RightTurn)
+		.map(|i| hull[i].clone())
+		.collect();
+	if corners.len() >= 3{
+		return corners;
+	}
+
+	let first = points[0].clone();
+	let last  = points[points.len()-1].clone();
+	if points.len() == 1{
+		vec!(first)
+	}
+	else{
+		vec!(first,last)
+	}
+}
+
+//Returns the vertex of the mini hull that the hull wraps to next from 'point', a vertex of the whole hull.
+//Binary searches the vertex furthest counter-clockwise as seen from 'point'.
+fn tangent<'a,P>(point: &P, hull: &'a [P]) -> &'a P where P: Point2DLike{
+	let len = hull.len();
+	let linear = || hull.iter().fold(&hull[0],|best,candidate| if wraps_further(point,best,candidate) {candidate} else {best});
+	if len < 3{
+		return linear();
+	}
+
+	//seen from outside the angles of the vertices rise to the tangent and then fall back
+	let left = |i: usize, j: usize| point.turn_direction(&hull[i % len],&hull[j % len]) == TurnDirection::LeftTurn;
+	let k = if !left(0,1) && left(len-1,0){
+		0
+	}
+	else{
+		let rising = left(0,1);
+		let (mut low,mut high) = (1,len);
+		while low < high{
+			let middle = (low + high) / 2;
+			let past = if rising {!left(middle,middle+1) || !left(0,middle)} else {!left(middle,middle+1) && left(0,middle)};
+			if past{
+				high = middle;
+			}
+			else{
+				low = middle + 1;
+			}
+		}
+		low % len
+	};
+
+	if same_position(&hull[k],point){
+		return &hull[(k+1)%len];
+	}
+	if left(k,k+len-1) || left(k,k+1){
+		//only when 'point' is a vertex of this mini hull
+		return linear();
+	}
+	[k+len-1,k+1].iter().fold(&hull[k],|best,&i| if wraps_further(point,best,&hull[i % len]) {&hull[i % len]} else {best})
+}
+
+//Returns true if the hull should wrap from 'point' to 'candidate' rather than to 'best'.
+//That is when 'candidate' is left of point->best, or in line with it but further away.
+fn wraps_further<P: Point2DLike>(point: &P, best: &P, candidate: &P) -> bool{
+	if same_position(best,point){
+		return !same_position(candidate,point);
+	}
+	match point.turn_direction(best,candidate){
+		TurnDirection::LeftTurn  => true,
+		TurnDirection::RightTurn => false,
+		TurnDirection::NoTurn    => point.x_then_y_partial_cmp(best) == best.x_then_y_partial_cmp(candidate),
+	}
+}
+
+///Preforms the Jarvis March/Gift Wrapping Algorithm on a set of points with the given options.
+pub fn jarvis_march_with_options<P>(points : &[P], options : &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	apply_options(jarvis_march(points),points,options)
+}
+
+///Preforms the Monotone Chain Algorithm on a set of points with the given options.
+pub fn monotone_chain_with_options<P>(points : &mut [P], options : &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	let hull = monotone_chain(points);
+	apply_options(hull,points,options)
+}
+
+///Preforms the Graham Scan Algorithm on a set of points with the given options.
+pub fn graham_scan_with_options<P>(points : &mut [P], options : &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	let hull = graham_scan(points);
+	apply_options(hull,points,options)
+}
+
+///Preforms the Quickhull Algorithm on a set of points with the given options.
+pub fn quickhull_with_options<P>(points : &[P], options : &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	apply_options(quickhull(points),points,options)
+}
+
+///Preforms Chan's Algorithm on a set of points with the given options.
+pub fn chan_with_options<P>(points : &[P], options : &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	apply_options(chan(points),points,options)
+}
+
+///Preforms the Jarvis March/Gift Wrapping Algorithm with the given options.
+///Returns an error for empty input or a NaN or infinite coordinate instead of panicking.
+pub fn try_jarvis_march<P>(points : &[P], options : &HullOptions) -> Result<Vec<P>,GeometryError> where P: Point2DLike + Clone{
+	check_points(points)?;
+	Ok(jarvis_march_with_options(points,options))
+}
+
+///Preforms the Monotone Chain Algorithm with the given options.
+///Returns an error for empty input or a NaN or infinite coordinate instead of panicking.
+pub fn try_monotone_chain<P>(points : &mut [P], options : &HullOptions) -> Result<Vec<P>,GeometryError> where P: Point2DLike + Clone{
+	check_points(points)?;
+	Ok(monotone_chain_with_options(points,options))
+}
+
+///Preforms the Graham Scan Algorithm with the given options.
+///Returns an error for empty input or a NaN or infinite coordinate instead of panicking.
+pub fn try_graham_scan<P>(points : &mut [P], options : &HullOptions) -> Result<Vec<P>,GeometryError> where P: Point2DLike + Clone{
+	check_points(points)?;
+	Ok(graham_scan_with_options(points,options))
+}
+
+///Preforms the Quickhull Algorithm with the given options.
+///Returns an error for empty input or a NaN or infinite coordinate instead of panicking.
+pub fn try_quickhull<P>(points : &[P], options : &HullOptions) -> Result<Vec<P>,GeometryError> where P: Point2DLike + Clone{
+	check_points(points)?;
+	Ok(quickhull_with_options(points,options))
+}
+
+///Preforms Chan's Algorithm with the given options.
+///Returns an error for empty input or a NaN or infinite coordinate instead of panicking.
+pub fn try_chan<P>(points : &[P], options : &HullOptions) -> Result<Vec<P>,GeometryError> where P: Point2DLike + Clone{
+	check_points(points)?;
+	Ok(chan_with_options(points,options))
+}
+
+//Adds the collinear points to and turns around a clockwise hull without them, as 'options' ask.
+pub(crate) fn apply_options<P>(mut hull: Vec<P>, points: &[P], options: &HullOptions) -> Vec<P> where P: Point2DLike + Clone{
+	//a hull of points on a line is not turned around
+	let reverse = options.orientation == Orientation::CounterClockwise && hull.len() > 2;
+	if options.include_collinear && hull.len() >= 2{
+		hull = with_collinear(hull,points);
+	}
+	if reverse{
+		hull[1..].reverse();
+	}
+	hull
+}
+
+//Inserts the points lying on the edges of a clockwise hull between its corners.
+fn with_collinear<P>(hull: Vec<P>, points: &[P]) -> Vec<P> where P: Point2DLike + Clone{
+	let len = hull.len();
+
+	//the right most point splits the hull into an upper and a lower chain, both listed in x then y order
+	//as indices of the corners, the edge with index i goes from hull[i] to the next corner clockwise
+	let right = (0..len).max_by(|&a,&b| hull[a].x_then_y_partial_cmp(&hull[b]).unwrap()).unwrap();
+	let upper : Vec<usize> = (0..=right).collect();
+	let lower : Vec<usize> = if len == 2 {Vec::new()} else {Some(0).into_iter().chain((right..len).rev()).collect()};
+
+	let mut on_edges : Vec<Vec<&P>> = vec!(Vec::new();len);
+	'points: for point in points{
+		for (chain,is_upper) in [(&upper,true),(&lower,false)].iter(){
+			let j = chain.partition_point(|&i| hull[i].x_then_y_partial_cmp(point) == Some(Ordering::Less));
+			if j == 0 || j == chain.len(){
+				continue;
+			}
+			let (before,after) = (chain[j-1],chain[j]);
+			if same_position(&hull[after],point){
+				continue 'points;
+			}
+			if hull[before].turn_direction(&hull[after],point) == TurnDirection::NoTurn{
+				//the lower chain is walked backwards going clockwise
+				on_edges[if *is_upper {before} else {after}].push(point);
+				continue 'points;
+			}
+		}
+	}
+
+	let mut result = Vec::new();
+	for (i,mut edge) in on_edges.into_iter().enumerate(){
+		result.push(hull[i].clone());
+
+		//the upper edges go in x then y order and the lower edges against it
+		edge.sort_by(|a,b| if i < right {a.x_then_y_partial_cmp(b).unwrap()} else {b.x_then_y_partial_cmp(a).unwrap()});
+		edge.dedup_by(|a,b| same_position(*a,*b));
+		result.extend(edge.into_iter().cloned());
+	}
+	result
+}
+
+//Points are compared by coordinates, so P does not need PartialEq.
+fn same_position<P: Point2DLike>(a: &P, b: &P) -> bool{
+	a.x_then_y_partial_cmp(b) == Some(Ordering::Equal)
+}
+
 #[cfg(test)]
 mod algorithms_test {
 	use super::*;
+	use crate::primatives2d::Point2D;
 	
     #[test]
     fn jarvis_march_test() {
@@ -173,10 +515,317 @@ mod algorithms_test {
 		
 		assert_eq!(graham_scan(&mut points),expected_results);
 	}
+
+	#[test]
+    fn quickhull_test() {
+        let points = vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,0.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(0.5,0.5)
+		);
+		let expected_results = vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(1.0,0.0)
+		);
+
+		assert_eq!(quickhull(&points),expected_results);
+		assert_eq!(quickhull(&[Point2D::new(1,1),Point2D::new(1,1)]),vec!(Point2D::new(1,1)));
+
+		//collinear points on the hull edges and on a line parallel to the base
+		let points = vec!(
+			Point2D::new(0,0),
+			Point2D::new(4,0),
+			Point2D::new(1,2),
+			Point2D::new(2,2),
+			Point2D::new(3,2),
+			Point2D::new(2,0),
+			Point2D::new(2,-1),
+			Point2D::new(2,1),
+		);
+		let expected_results = vec!(
+			Point2D::new(0,0),
+			Point2D::new(1,2),
+			Point2D::new(3,2),
+			Point2D::new(4,0),
+			Point2D::new(2,-1)
+		);
+		assert_eq!(quickhull(&points),expected_results);
+		assert_eq!(monotone_chain(&mut points.clone()),expected_results);
+
+		//grids whose spacing is not representable, the distances from the base edge round differently
+		use rand::RngExt;
+		for _ in 0..200{
+			let points : Vec<Point2D<f64>> = (0..30).map(|_| Point2D::new(
+				0.1 + rand::rng().random_range(0..8) as f64 / 3.0,
+				0.2 + rand::rng().random_range(0..8) as f64 * 0.3)).collect();
+			let hull = jarvis_march(&points);
+			assert_eq!(quickhull(&points),hull);
+			assert_eq!(monotone_chain(&mut points.clone()),hull);
+		}
+
+		//products of coordinates this large do not fit in an i64 or an i128
+		for &scale in &[1i64 << 40,1 << 60,i64::MAX / 8]{
+			let points : Vec<Point2D<i64>> = (0..50).map(|_| Point2D::new(
+				rand::rng().random_range(-7..=7) * scale,
+				rand::rng().random_range(-7..=7) * scale)).collect();
+			let hull = jarvis_march(&points);
+			assert_eq!(quickhull(&points),hull);
+			assert_eq!(monotone_chain(&mut points.clone()),hull);
+		}
+		let corners = [Point2D::new(i64::MIN,i64::MIN),Point2D::new(i64::MIN,i64::MAX),Point2D::new(i64::MAX,i64::MAX),Point2D::new(i64::MAX,i64::MIN)];
+		let mut points = corners.to_vec();
+		points.push(Point2D::new(0,i64::MAX));
+		points.push(Point2D::new(-5,3));
+		assert_eq!(quickhull(&points),corners.to_vec());
+	}
+	
+	#[test]
+    fn chan_test() {
+        let points = vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,0.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(0.5,0.5)
+		);
+		let expected_results = vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(1.0,0.0)
+		);
+
+		assert_eq!(chan(&points),expected_results);
+		assert_eq!(chan(&[Point2D::new(1,1),Point2D::new(1,1)]),vec!(Point2D::new(1,1)));
+		assert_eq!(chan(&[Point2D::new(0,0),Point2D::new(2,2),Point2D::new(1,1)]),vec!(Point2D::new(0,0),Point2D::new(2,2)));
+
+		//grid with collinear and repeated points spread over many mini hulls
+		let mut points = Vec::new();
+		for i in 0..20{
+			for j in 0..20{
+				points.push(Point2D::new((i * 7) % 20,(j * 13) % 20));
+				points.push(Point2D::new(j,i));
+			}
+		}
+		let expected_results = vec!(
+			Point2D::new(0,0),
+			Point2D::new(0,19),
+			Point2D::new(19,19),
+			Point2D::new(19,0)
+		);
+		assert_eq!(chan(&points),expected_results);
+		assert_eq!(quickhull(&points),expected_results);
+
+		//every point on the hull, more than the first guess of the hull size
+		let points : Vec<Point2D<i64>> = (0..40).map(|i| Point2D::new(i,i * i)).collect();
+		let mut expected_results = points.clone();
+		expected_results[1..].reverse();
+		assert_eq!(chan(&points),expected_results);
+		assert_eq!(quickhull(&points),expected_results);
+
+		//random points on a small grid and on a circle, with many collinear and repeated points
+		use rand::RngExt;
+		for _ in 0..20{
+			let mut points : Vec<Point2D<i64>> = (0..2000).map(|_| Point2D::new(rand::rng().random_range(0..50),rand::rng().random_range(0..50))).collect();
+			assert_eq!(chan(&points),quickhull(&points));
+
+			for i in 0..500{
+				let angle = (i as f64) * 0.0125;
+				points.push(Point2D::new((angle.cos() * 1000.0).round() as i64,(angle.sin() * 1000.0).round() as i64));
+			}
+			assert_eq!(chan(&points),quickhull(&points));
+		}
+	}
+	
+	//runs every algorithm taking a slice and checks they agree
+	fn static_hulls<T: Coordinate>(points: &[Point2D<T>], options: &HullOptions) -> Vec<Point2D<T>>{
+		let hull = jarvis_march_with_options(points,options);
+		assert_eq!(monotone_chain_with_options(&mut points.to_vec(),options),hull);
+		assert_eq!(graham_scan_with_options(&mut points.to_vec(),options),hull);
+		assert_eq!(quickhull_with_options(points,options),hull);
+		assert_eq!(chan_with_options(points,options),hull);
+		hull
+	}
+
+	//runs every algorithm and checks they agree
+	fn all_float_hulls(points: &[Point2D<f64>], options: &HullOptions) -> Vec<Point2D<f64>>{
+		let hull = static_hulls(points,options);
+		let mut dynamic = crate::algorithms::dynamic_hull::DynamicHull::new();
+		for &point in points{
+			dynamic.insert(point);
+		}
+		assert_eq!(dynamic.hull_with_options(options),hull);
+		hull
+	}
+
+	//runs every algorithm and checks they agree
+	fn all_hulls(points: &[Point2D<i64>], options: &HullOptions) -> Vec<Point2D<i64>>{
+		let hull = static_hulls(points,options);
+
+		let mut dynamic = crate::algorithms::dynamic_hull::DynamicHull::new();
+		for point in points{
+			dynamic.insert(Point2D::new(point.x as f64,point.y as f64));
+		}
+		let dynamic_hull : Vec<Point2D<i64>> = dynamic.hull_with_options(options).iter().map(|p| Point2D::new(p.x as i64,p.y as i64)).collect();
+		assert_eq!(dynamic_hull,hull);
+		hull
+	}
+
+	fn options(include_collinear: bool, orientation: Orientation) -> HullOptions{
+		HullOptions{include_collinear,orientation}
+	}
+
+	#[test]
+    fn hull_options_grid_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let mut points = Vec::new();
+		for x in 0..3{
+			for y in 0..3{
+				points.push(p(x,y));
+			}
+		}
+		points.push(p(2,1));
+		points.push(p(0,0));
+		points.reverse();
+
+		assert_eq!(all_hulls(&points,&HullOptions::default()),vec!(p(0,0),p(0,2),p(2,2),p(2,0)));
+		assert_eq!(all_hulls(&points,&options(true,Orientation::Clockwise)),
+			vec!(p(0,0),p(0,1),p(0,2),p(1,2),p(2,2),p(2,1),p(2,0),p(1,0)));
+		assert_eq!(all_hulls(&points,&options(false,Orientation::CounterClockwise)),vec!(p(0,0),p(2,0),p(2,2),p(0,2)));
+		assert_eq!(all_hulls(&points,&options(true,Orientation::CounterClockwise)),
+			vec!(p(0,0),p(1,0),p(2,0),p(2,1),p(2,2),p(1,2),p(0,2),p(0,1)));
+
+		//a diamond, every edge slanted
+		let points : Vec<Point2D<i64>> = (-4i64..=4).flat_map(|x| (-4i64..=4).map(move |y| Point2D::new(x,y))).filter(|p| p.x.abs() + p.y.abs() <= 4).collect();
+		let hull = all_hulls(&points,&options(true,Orientation::Clockwise));
+		assert_eq!(hull.len(),16);
+		assert_eq!(&hull[..6],&[p(-4,0),p(-3,1),p(-2,2),p(-1,3),p(0,4),p(1,3)]);
+		assert_eq!(all_hulls(&points,&HullOptions::default()),vec!(p(-4,0),p(0,4),p(4,0),p(0,-4)));
+
+		//random grids with many collinear and repeated points
+		use rand::RngExt;
+		for _ in 0..50{
+			let points : Vec<Point2D<i64>> = (0..60).map(|_| p(rand::rng().random_range(0..8),rand::rng().random_range(0..8))).collect();
+			for &include_collinear in &[false,true]{
+				for &orientation in &[Orientation::Clockwise,Orientation::CounterClockwise]{
+					all_hulls(&points,&options(include_collinear,orientation));
+				}
+			}
+		}
+	}
+
+	#[test]
+    fn hull_options_float_grid_test() {
+		//0.1 and 0.3 have no exact binary form, so the slanted grid lines are only nearly straight
+		let p = |x,y| Point2D::new(x,y);
+		let grid = |i: i32,j: i32| p(0.1 + i as f64 * 0.1,0.2 + j as f64 * 0.3);
+		let mut points = Vec::new();
+		for i in 0..3{
+			for j in 0..3{
+				points.push(grid(i,j));
+			}
+		}
+		points.push(grid(2,1));
+		points.reverse();
+
+		assert_eq!(all_float_hulls(&points,&HullOptions::default()),vec!(grid(0,0),grid(0,2),grid(2,2),grid(2,0)));
+		assert_eq!(all_float_hulls(&points,&options(true,Orientation::Clockwise)),
+			vec!(grid(0,0),grid(0,1),grid(0,2),grid(1,2),grid(2,2),grid(2,1),grid(2,0),grid(1,0)));
+		assert_eq!(all_float_hulls(&points,&options(true,Orientation::CounterClockwise)),
+			vec!(grid(0,0),grid(1,0),grid(2,0),grid(2,1),grid(2,2),grid(1,2),grid(0,2),grid(0,1)));
+
+		//a diamond of such points, whether its edge points are exactly on the edges depends on rounding
+		let points : Vec<Point2D<f64>> = (-4i32..=4).flat_map(|i| (-4i32..=4).map(move |j| (i,j))).filter(|(i,j)| i.abs() + j.abs() <= 4).map(|(i,j)| grid(i,j)).collect();
+		for &orientation in &[Orientation::Clockwise,Orientation::CounterClockwise]{
+			let hull = all_float_hulls(&points,&options(true,orientation));
+			for corner in &[grid(-4,0),grid(0,4),grid(4,0),grid(0,-4)]{
+				assert!(hull.contains(corner));
+			}
+		}
+
+		//random grids with many nearly collinear and repeated points
+		use rand::RngExt;
+		for _ in 0..50{
+			let points : Vec<Point2D<f64>> = (0..60).map(|_| grid(rand::rng().random_range(0..8),rand::rng().random_range(0..8))).collect();
+			for &include_collinear in &[false,true]{
+				for &orientation in &[Orientation::Clockwise,Orientation::CounterClockwise]{
+					all_float_hulls(&points,&options(include_collinear,orientation));
+				}
+			}
+		}
+	}
+
+	#[test]
+    fn hull_options_line_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let points = vec!(p(2,2),p(0,0),p(3,3),p(1,1),p(2,2));
+
+		assert_eq!(all_hulls(&points,&HullOptions::default()),vec!(p(0,0),p(3,3)));
+		assert_eq!(all_hulls(&points,&options(false,Orientation::CounterClockwise)),vec!(p(0,0),p(3,3)));
+		assert_eq!(all_hulls(&points,&options(true,Orientation::Clockwise)),vec!(p(0,0),p(1,1),p(2,2),p(3,3)));
+		assert_eq!(all_hulls(&points,&options(true,Orientation::CounterClockwise)),vec!(p(0,0),p(1,1),p(2,2),p(3,3)));
+
+		let vertical = vec!(p(0,3),p(0,1),p(0,2),p(0,0));
+		assert_eq!(all_hulls(&vertical,&HullOptions::default()),vec!(p(0,0),p(0,3)));
+		assert_eq!(all_hulls(&vertical,&options(true,Orientation::Clockwise)),vec!(p(0,0),p(0,1),p(0,2),p(0,3)));
+
+		let single = vec!(p(5,5),p(5,5));
+		assert_eq!(all_hulls(&single,&options(true,Orientation::CounterClockwise)),vec!(p(5,5)));
+	}
+	
+	#[test]
+    fn try_hull_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let options = HullOptions::default();
+		let try_all = |points: &[Point2D<f64>]| {
+			let hull = try_jarvis_march(points,&options);
+			assert_eq!(try_monotone_chain(&mut points.to_vec(),&options),hull);
+			assert_eq!(try_graham_scan(&mut points.to_vec(),&options),hull);
+			assert_eq!(try_quickhull(points,&options),hull);
+			assert_eq!(try_chan(points,&options),hull);
+			hull
+		};
+
+		assert_eq!(try_all(&[]),Err(GeometryError::EmptyInput));
+		assert_eq!(try_all(&[p(0.0,0.0),p(1.0,f64::NAN)]),Err(GeometryError::NonFiniteCoordinate(1)));
+		assert_eq!(try_all(&[p(f64::NEG_INFINITY,0.0),p(1.0,1.0)]),Err(GeometryError::NonFiniteCoordinate(0)));
+
+		assert_eq!(try_all(&[p(1.0,2.0)]),Ok(vec!(p(1.0,2.0))));
+		assert_eq!(try_all(&[p(1.0,2.0),p(0.0,0.0)]),Ok(vec!(p(0.0,0.0),p(1.0,2.0))));
+		assert_eq!(try_all(&[p(1.0,0.0),p(3.0,0.0),p(2.0,0.0)]),Ok(vec!(p(1.0,0.0),p(3.0,0.0))));
+		assert_eq!(try_all(&[p(0.0,0.0),p(1.0,1.0),p(0.0,1.0),p(1.0,1.0)]),Ok(vec!(p(0.0,0.0),p(0.0,1.0),p(1.0,1.0))));
+
+		//extreme finite coordinates, where differences and products overflow without exact predicates
+		assert_eq!(try_all(&[p(-1e308,-1e308),p(1e308,1e308),p(-1e308,1e308),p(1e308,-1e308),p(0.0,0.0),p(f64::MAX,0.0)]),
+			Ok(vec!(p(-1e308,-1e308),p(-1e308,1e308),p(1e308,1e308),p(f64::MAX,0.0),p(1e308,-1e308))));
+		assert_eq!(try_all(&[p(f64::MIN,f64::MIN),p(f64::MAX,f64::MAX),p(0.0,0.0),p(1.0,1.0)]),
+			Ok(vec!(p(f64::MIN,f64::MIN),p(f64::MAX,f64::MAX))));
+		//tiny coordinates, where the products become subnormal without exact predicates
+		let t = 1e-200;
+		assert_eq!(try_all(&[p(0.0,0.0),p(t,0.0),p(t,t),p(0.0,t),p(0.5 * t,0.5 * t)]),
+			Ok(vec!(p(0.0,0.0),p(0.0,t),p(t,t),p(t,0.0))));
+		assert_eq!(try_all(&[p(0.0,0.0),p(5e-324,5e-324),p(1e-323,1e-323),p(0.0,5e-324)]),
+			Ok(vec!(p(0.0,0.0),p(0.0,5e-324),p(1e-323,1e-323))));
+		let q = |x,y| Point2D::new(x,y);
+		let extreme = [q(i64::MIN,i64::MIN),q(i64::MAX,i64::MAX),q(i64::MIN,i64::MAX),q(i64::MAX,i64::MIN),q(0,0),q(i64::MIN + 1,0),q(0,i64::MAX - 1)];
+		let hull = try_jarvis_march(&extreme,&options);
+		assert_eq!(hull,Ok(vec!(q(i64::MIN,i64::MIN),q(i64::MIN,i64::MAX),q(i64::MAX,i64::MAX),q(i64::MAX,i64::MIN))));
+		assert_eq!(try_monotone_chain(&mut extreme.to_vec(),&options),hull);
+		assert_eq!(try_graham_scan(&mut extreme.to_vec(),&options),hull);
+		assert_eq!(try_quickhull(&extreme,&options),hull);
+		assert_eq!(try_chan(&extreme,&options),hull);
+
+		assert_eq!(try_quickhull(&[(0,0),(2,0),(1,0)],&HullOptions{include_collinear: true,orientation: Orientation::CounterClockwise}),
+			Ok(vec!((0,0),(1,0),(2,0))));
+	}
 	
 	#[test]
     fn random_stress_test() {
-		use rand::Rng;
+		use rand::RngExt;
 	
         let mut points = vec!(
 			Point2D::new(0.0,0.0),
@@ -187,7 +836,7 @@ mod algorithms_test {
 		);    
 
 		for _ in 0..100000{
-			points.push( Point2D::new(rand::thread_rng().gen(),rand::thread_rng().gen()));
+			points.push( Point2D::new(rand::rng().random(),rand::rng().random()));
 		}
 		
 		let expected_results = vec!(
@@ -199,24 +848,154 @@ mod algorithms_test {
 		
 		assert_eq!(graham_scan(&mut points),expected_results);
 		assert_eq!(monotone_chain(&mut points),expected_results);
-		assert_eq!(jarvis_march(&mut points),expected_results);
+		assert_eq!(jarvis_march(&points),expected_results);
+		assert_eq!(quickhull(&points),expected_results);
+		assert_eq!(chan(&points),expected_results);
 		
 		
 		let mut points = Vec::new();   
 		points.push(Point2D::new(0.5,0.5));
 		
 		for _ in 0..100000{
-			points.push( Point2D::new(rand::thread_rng().gen(),rand::thread_rng().gen()));
+			points.push( Point2D::new(rand::rng().random(),rand::rng().random()));
 		}
 		
 		let graham   = graham_scan(&mut points);
 		let monotone = monotone_chain(&mut points);
-		let jarvis   = jarvis_march(&mut points);
+		let jarvis   = jarvis_march(&points);
+		let quick    = quickhull(&points);
+		let chan     = chan(&points);
 		assert_eq!(graham,monotone);
 		assert_eq!(graham,jarvis);
+		assert_eq!(graham,quick);
+		assert_eq!(graham,chan);
 		
 		
 	}
-	
+
+	#[test]
+    fn integer_hull_test() {
+		//nearly collinear points far from the origin, exact on i64
+		let base = 1i64 << 40;
+		let mut points = vec!(
+			Point2D::new(base,base),
+			Point2D::new(base+3,base+3),
+			Point2D::new(base+2,base+1),
+			Point2D::new(base+1,base+1),
+			Point2D::new(base,base+3)
+		);
+		let expected_results = vec!(
+			Point2D::new(base,base),
+			Point2D::new(base,base+3),
+			Point2D::new(base+3,base+3),
+			Point2D::new(base+2,base+1)
+		);
+
+		assert_eq!(jarvis_march(&points),expected_results);
+		assert_eq!(quickhull(&points),expected_results);
+		assert_eq!(chan(&points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+		assert_eq!(graham_scan(&mut points),expected_results);
+	}
+
+	#[cfg(feature = "rational")]
+	#[test]
+    fn rational_hull_test() {
+		use num_rational::Ratio;
+		let r = |n,d| Ratio::new(n,d);
+		let mut points = vec!(
+			Point2D::new(r(0i64,1),r(0,1)),
+			Point2D::new(r(1,3),r(1,3)),
+			Point2D::new(r(0,1),r(1,1)),
+			Point2D::new(r(1,1),r(1,1)),
+			Point2D::new(r(1,1),r(0,1))
+		);
+		let expected_results = vec!(
+			Point2D::new(r(0,1),r(0,1)),
+			Point2D::new(r(0,1),r(1,1)),
+			Point2D::new(r(1,1),r(1,1)),
+			Point2D::new(r(1,1),r(0,1))
+		);
+
+		assert_eq!(jarvis_march(&points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+		assert_eq!(graham_scan(&mut points),expected_results);
+	}
+
+	#[test]
+    fn graham_scan_collinear_pivot_test() {
+		//points straight above the pivot and on the ray through the pivot
+		let mut points = vec!(
+			Point2D::new(0i64,0),
+			Point2D::new(0,2),
+			Point2D::new(0,1),
+			Point2D::new(2,2),
+			Point2D::new(2,0),
+			Point2D::new(1,1),
+			Point2D::new(-1i64 << 40,-1i64 << 40),
+		);
+		let expected_results = vec!(
+			Point2D::new(-1i64 << 40,-1i64 << 40),
+			Point2D::new(0,2),
+			Point2D::new(2,2),
+			Point2D::new(2,0)
+		);
+
+		assert_eq!(graham_scan(&mut points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+
+		//a pivot far down, nothing may be computed below it
+		let low = i64::MIN + 1;
+		let mut points = vec!(Point2D::new(0,low),Point2D::new(1,6),Point2D::new(0,5),Point2D::new(3,-7),Point2D::new(i64::MAX,low));
+		let expected_results = vec!(Point2D::new(0,low),Point2D::new(0,5),Point2D::new(1,6),Point2D::new(i64::MAX,low));
+		assert_eq!(graham_scan(&mut points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+
+		let mut points = vec!(Point2D::new(0.0,-1e308),Point2D::new(0.5,0.0),Point2D::new(1.0,0.0),Point2D::new(2.0,3.0));
+		let expected_results = vec!(Point2D::new(0.0,-1e308),Point2D::new(0.5,0.0),Point2D::new(2.0,3.0));
+		assert_eq!(graham_scan(&mut points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+	}
+
+	#[derive(Clone,Debug,PartialEq)]
+	struct LatLon{
+		lat : f64,
+		lon : f64,
+	}
+
+	impl Point2DLike for LatLon {
+		type Coordinate = f64;
+		fn x(&self) -> f64{
+			self.lon
+		}
+		fn y(&self) -> f64{
+			self.lat
+		}
+	}
+
+	#[test]
+    fn point2d_like_hull_test() {
+		let l = |lat,lon| LatLon{lat,lon};
+		let mut points = vec!(
+			l(0.0,0.0),
+			l(1.0,0.0),
+			l(0.0,1.0),
+			l(1.0,1.0),
+			l(0.5,0.5)
+		);
+		let expected_results = vec!(
+			l(0.0,0.0),
+			l(1.0,0.0),
+			l(1.0,1.0),
+			l(0.0,1.0)
+		);
+
+		assert_eq!(jarvis_march(&points),expected_results);
+		assert_eq!(monotone_chain(&mut points),expected_results);
+		assert_eq!(graham_scan(&mut points),expected_results);
+
+		let mut tuples = vec!((0,0),(2,0),(1,1),(0,2),(2,2));
+		assert_eq!(monotone_chain(&mut tuples),vec!((0,0),(0,2),(2,2),(2,0)));
+	}
 
 }
@@ -0,0 +1,396 @@
+//!Algorithms for creating convex hulls of a set of Point3Ds.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::primatives2d::Point2D;
+use crate::primatives3d::{Point3D,Vector3D,PlaneSide};
+use crate::point2d_like::Point2DLike;
+use crate::coordinate::FloatCoordinate;
+use crate::algorithms::convex_hull;
+
+
+///Convex hull of a set of 3D points as a closed triangulated surface.
+///Every triangle is counter-clockwise seen from outside the hull, so its normal points outwards.
+///If all the points lie in a plane the hull is flat and covered by triangles facing both ways,
+///if they lie on a line or there are fewer than 3 distinct points there are no triangles.
+#[derive(Clone,Debug,PartialEq)]
+pub struct ConvexHull3D<T: FloatCoordinate>{
+	///Triangles of the hull surface.
+	pub triangles : Vec<[Point3D<T>;3]>,
+	///The same triangles as indices into the points the hull was made from.
+	pub indices   : Vec<[usize;3]>,
+}
+
+impl<T: FloatCoordinate> ConvexHull3D<T >{
+	///Returns the indices of the points on the hull, sorted and without repeats.
+	pub fn vertex_indices(&self) -> Vec<usize>{
+		let mut vertices : Vec<usize> = self.indices.iter().flat_map(|face| face.iter().cloned()).collect();
+		vertices.sort_unstable();
+		vertices.dedup();
+		vertices
+	}
+
+	///Returns the total area of the triangles.
+	pub fn surface_area(&self) -> T{
+		let two = T::one() + T::one();
+		self.triangles.iter().fold(T::zero(),|area,[a,b,c]| area + (*b - *a).cross(&(*c - *a)).norm()) / two
+	}
+
+	///Returns the volume enclosed by the triangles, zero for a flat hull.
+	pub fn volume(&self) -> T{
+		let reference = match self.triangles.first(){
+			Some(triangle) => triangle[0],
+			None => return T::zero(),
+		};
+		let six = T::from(6.0).unwrap();
+		self.triangles.iter().fold(T::zero(),|volume,[a,b,c]|
+			volume + (*a - reference).dot(&(*b - reference).cross(&(*c - reference)))) / six
+	}
+}
+
+///Preforms the 3D Quickhull Algorithm on a set of points.
+///Points with a non finite coordinate are ignored, as are points on the hull that are not corners.
+///Very large or very small coordinates are scaled by a power of two first, which only changes the result
+///for coordinates so much smaller than the largest that they underflow.
+pub fn quickhull<T: FloatCoordinate>(points : &[Point3D<T>]) -> ConvexHull3D<T>{
+	//https://en.wikipedia.org/wiki/Quickhull
+	//Barber, Dobkin and Huhdanpaa, The Quickhull Algorithm for Convex Hulls, 1996
+
+	let candidates : Vec<usize> = (0..points.len())
+		.filter(|&i| points[i].x.is_finite() && points[i].y.is_finite() && points[i].z.is_finite())
+		.collect();
+	let scaled = rescale(points,&candidates);
+
+	let indices = match initial_simplex(&scaled,&candidates){
+		Simplex::Tetrahedron(simplex) => wrap(&scaled,&candidates,simplex),
+		Simplex::Plane(a,b,c) => flat_hull(&scaled,&candidates,[a,b,c]),
+		Simplex::Degenerate => Vec::new(),
+	};
+
+	ConvexHull3D{
+		triangles: indices.iter().map(|&[a,b,c]| [points[a],points[b],points[c]]).collect(),
+		indices,
+	}
+}
+
+//The predicates and the sizes used to pick points multiply up to three coordinate differences,
+//so coordinates far from one are brought near it first to keep those products finite and non zero.
+fn rescale<T: FloatCoordinate>(points: &[Point3D<T>], candidates: &[usize]) -> Vec<Point3D<T>>{
+	let largest = candidates.iter().fold(T::zero(),|largest,&i| largest.max(points[i].x.abs()).max(points[i].y.abs()).max(points[i].z.abs()));
+	let high = T::max_value().sqrt().sqrt();
+	let low = T::min_positive_value().sqrt().sqrt();
+	if largest.is_zero() || (largest >= low && largest <= high){
+		return points.to_vec();
+	}
+
+	//multiplying by a power of two is exact, it is done in two steps so neither factor overflows
+	let (mantissa,exponent,_) = largest.integer_decode();
+	let exponent = exponent as i32 + 63 - mantissa.leading_zeros() as i32;
+	let two = T::one() + T::one();
+	let (first,second) = (two.powi(-exponent / 2),two.powi(exponent / 2 - exponent));
+	points.iter().map(|p| Point3D::new(p.x * first * second,p.y * first * second,p.z * first * second)).collect()
+}
+
+//Orders sizes for picking far away points, incomparable sizes count as equal.
+fn size_cmp<T: FloatCoordinate>(a: T, b: T) -> Ordering{
+	a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+enum Simplex{
+	Tetrahedron([usize;4]),
+	Plane(usize,usize,usize),
+	Degenerate,
+}
+
+//Finds 4 points not in a plane, or 3 points not on a line if all points are in a plane.
+fn initial_simplex<T: FloatCoordinate>(points: &[Point3D<T>], candidates: &[usize]) -> Simplex{
+	let first = match candidates.iter().min_by(|&&a,&&b| points[a].x_then_y_then_z_cmp(&points[b])){
+		Some(&first) => first,
+		None => return Simplex::Degenerate,
+	};
+	let p0 = points[first];
+
+	//far apart points give a better shaped start
+	let furthest = |valid: &dyn Fn(&Point3D<T>) -> bool, size: &dyn Fn(&Point3D<T>) -> T|
+		candidates.iter().cloned().filter(|&i| valid(&points[i]))
+			.max_by(|&a,&b| size_cmp(size(&points[a]),size(&points[b])));
+
+	let second = match furthest(&|p| *p != p0,&|p| p.distance_squared(&p0)){
+		Some(second) => second,
+		None => return Simplex::Degenerate,
+	};
+	let p1 = points[second];
+
+	let third = match furthest(&|p| !p0.is_collinear(&p1,p),&|p| (p1 - p0).cross(&(*p - p0)).norm_squared()){
+		Some(third) => third,
+		None => return Simplex::Degenerate,
+	};
+	let p2 = points[third];
+
+	let normal = (p1 - p0).cross(&(p2 - p0));
+	match furthest(&|p| p.plane_side(&p0,&p1,&p2) != PlaneSide::OnPlane,&|p| normal.dot(&(*p - p0)).abs()){
+		Some(fourth) => Simplex::Tetrahedron([first,second,third,fourth]),
+		None => Simplex::Plane(first,second,third),
+	}
+}
+
+struct Face{
+	vertices : [usize;3],
+	outside  : Vec<usize>,
+	alive    : bool,
+}
+
+//Grows the hull from the tetrahedron one furthest outside point at a time.
+fn wrap<T: FloatCoordinate>(points: &[Point3D<T>], candidates: &[usize], simplex: [usize;4]) -> Vec<[usize;3]>{
+	let mut faces : Vec<Face> = Vec::new();
+	//the face each directed edge belongs to, the face across it has the reversed edge
+	let mut edges : HashMap<(usize,usize),usize> = HashMap::new();
+
+	let is_above = |point: usize, vertices: &[usize;3]|
+		points[point].plane_side(&points[vertices[0]],&points[vertices[1]],&points[vertices[2]]) == PlaneSide::Above;
+
+	let add_face = |faces: &mut Vec<Face>, edges: &mut HashMap<(usize,usize),usize>, vertices: [usize;3]| -> usize{
+		let index = faces.len();
+		for i in 0..3{
+			edges.insert((vertices[i],vertices[(i+1)%3]),index);
+		}
+		faces.push(Face{vertices,outside: Vec::new(),alive: true});
+		index
+	};
+
+	//each face of the tetrahedron is turned so the vertex opposite it is below it
+	let [a,b,c,d] = simplex;
+	for &(p,q,r,opposite) in &[(a,b,c,d),(a,b,d,c),(a,c,d,b),(b,c,d,a)]{
+		let vertices = if is_above(opposite,&[p,q,r]) {[p,r,q]} else {[p,q,r]};
+		add_face(&mut faces,&mut edges,vertices);
+	}
+
+	for &point in candidates{
+		if simplex.contains(&point){
+			continue;
+		}
+		if let Some(face) = faces.iter_mut().find(|face| is_above(point,&face.vertices)){
+			face.outside.push(point);
+		}
+	}
+
+	let mut pending : Vec<usize> = (0..faces.len()).collect();
+	while let Some(start) = pending.pop(){
+		if !faces[start].alive || faces[start].outside.is_empty(){
+			continue;
+		}
+
+		let eye = *faces[start].outside.iter()
+			.max_by(|&&p,&&q| size_cmp(height(points,&faces[start].vertices,p),height(points,&faces[start].vertices,q)))
+			.unwrap();
+
+		//the faces seen from the eye form a connected region, its boundary is the horizon
+		let mut visible = vec!(start);
+		faces[start].alive = false;
+		let mut horizon = Vec::new();
+		let mut i = 0;
+		while i < visible.len(){
+			let vertices = faces[visible[i]].vertices;
+			for j in 0..3{
+				let edge = (vertices[j],vertices[(j+1)%3]);
+				let neighbour = edges[&(edge.1,edge.0)];
+				if !faces[neighbour].alive{
+					continue;
+				}
+				if is_above(eye,&faces[neighbour].vertices){
+					faces[neighbour].alive = false;
+					visible.push(neighbour);
+				}
+				else{
+					horizon.push(edge);
+				}
+			}
+			i += 1;
+		}
+
+		let mut orphans = Vec::new();
+		for &face in &visible{
+			orphans.append(&mut faces[face].outside);
+			let vertices = faces[face].vertices;
+			for j in 0..3{
+				edges.remove(&(vertices[j],vertices[(j+1)%3]));
+			}
+		}
+
+		let new_faces : Vec<usize> = horizon.iter().map(|&(p,q)| add_face(&mut faces,&mut edges,[p,q,eye])).collect();
+		for point in orphans{
+			if point == eye{
+				continue;
+			}
+			if let Some(&face) = new_faces.iter().find(|&&face| is_above(point,&faces[face].vertices)){
+				faces[face].outside.push(point);
+			}
+		}
+		pending.extend(new_faces);
+	}
+
+	faces.into_iter().filter(|face| face.alive).map(|face| face.vertices).collect()
+}
+
+//Distance of the point above the face, scaled by twice the face's area.
+fn height<T: FloatCoordinate>(points: &[Point3D<T>], vertices: &[usize;3], point: usize) -> T{
+	let [a,b,c] = [points[vertices[0]],points[vertices[1]],points[vertices[2]]];
+	(b - a).cross(&(c - a)).dot(&(points[point] - a))
+}
+
+//Point projected to the plane of a flat hull, remembering where it came from.
+#[derive(Clone)]
+struct Projected<T: FloatCoordinate>{
+	point : Point2D<T>,
+	index : usize,
+}
+
+impl<T: FloatCoordinate> Point2DLike for Projected<T> {
+	type Coordinate = T;
+	fn x(&self) -> T{
+		self.point.x
+	}
+	fn y(&self) -> T{
+		self.point.y
+	}
+}
+
+//Covers the hull of points in one plane with triangles facing both ways.
+fn flat_hull<T: FloatCoordinate>(points: &[Point3D<T>], candidates: &[usize], plane: [usize;3]) -> Vec<[usize;3]>{
+	let [a,b,c] = [points[plane[0]],points[plane[1]],points[plane[2]]];
+	let normal : Vector3D<T> = (b - a).cross(&(c - a));
+
+	//dropping the axis the plane faces most keeps the projection from collapsing
+	let (nx,ny,nz) = (normal.x.abs(),normal.y.abs(),normal.z.abs());
+	let projected : Vec<Projected<T>> = candidates.iter().map(|&index|{
+		let p = points[index];
+		let point = if nx >= ny && nx >= nz {Point2D::new(p.y,p.z)} else if ny >= nz {Point2D::new(p.z,p.x)} else {Point2D::new(p.x,p.y)};
+		Projected{point,index}
+	}).collect();
+
+	let polygon : Vec<usize> = convex_hull::quickhull(&projected).into_iter().map(|p| p.index).collect();
+
+	let mut triangles = Vec::new();
+	for i in 1..polygon.len()-1{
+		triangles.push([polygon[0],polygon[i],polygon[i+1]]);
+		triangles.push([polygon[0],polygon[i+1],polygon[i]]);
+	}
+	triangles
+}
+
+
+#[cfg(test)]
+mod convex_hull_3d_test {
+	use super::*;
+
+	fn p(x: f64,y: f64,z: f64) -> Point3D<f64>{
+		Point3D::new(x,y,z)
+	}
+
+	//every point is below or on every face and every edge is shared by exactly 2 faces
+	fn check_hull(points: &[Point3D<f64>], hull: &ConvexHull3D<f64>){
+		let mut edges = HashMap::new();
+		for face in &hull.indices{
+			for point in points{
+				assert_ne!(point.plane_side(&points[face[0]],&points[face[1]],&points[face[2]]),PlaneSide::Above);
+			}
+			for i in 0..3{
+				assert!(edges.insert((face[i],face[(i+1)%3]),()).is_none());
+			}
+		}
+		for &(a,b) in edges.keys(){
+			assert!(edges.contains_key(&(b,a)));
+		}
+	}
+
+	#[test]
+    fn cube_test() {
+		let mut points = Vec::new();
+		for &x in &[0.0,0.5,1.0]{
+			for &y in &[0.0,0.5,1.0]{
+				for &z in &[0.0,0.5,1.0]{
+					points.push(p(x,y,z));
+				}
+			}
+		}
+		points.push(p(0.0,0.0,0.0));
+
+		let hull = quickhull(&points);
+		check_hull(&points,&hull);
+		assert_eq!(hull.vertex_indices(),vec!(0,2,6,8,18,20,24,26));
+		assert_eq!(hull.indices.len(),12);
+		assert_eq!(hull.volume(),1.0);
+		assert_eq!(hull.surface_area(),6.0);
+		assert_eq!(hull.triangles[0],[points[hull.indices[0][0]],points[hull.indices[0][1]],points[hull.indices[0][2]]]);
+	}
+
+	#[test]
+    fn random_test() {
+		use rand::RngExt;
+
+		for _ in 0..20{
+			let mut points : Vec<Point3D<f64>> = (0..500).map(|_| p(rand::rng().random(),rand::rng().random(),rand::rng().random())).collect();
+			//points on a sphere are all corners of the hull
+			for i in 0..100{
+				let (a,b) = (i as f64 * 0.7,i as f64 * 1.3);
+				points.push(p(a.cos() * b.sin() * 3.0,a.sin() * b.sin() * 3.0,b.cos() * 3.0));
+			}
+			let hull = quickhull(&points);
+			check_hull(&points,&hull);
+			assert!(hull.volume() > 0.0);
+			assert_eq!(hull.indices.len(),2 * hull.vertex_indices().len() - 4);
+		}
+
+		//small integer grid with many coplanar points
+		for _ in 0..20{
+			let points : Vec<Point3D<f64>> = (0..200).map(|_| p(
+				rand::rng().random_range(0..5) as f64,
+				rand::rng().random_range(0..5) as f64,
+				rand::rng().random_range(0..5) as f64)).collect();
+			let hull = quickhull(&points);
+			check_hull(&points,&hull);
+		}
+	}
+
+	#[test]
+    fn extreme_scale_test() {
+		use rand::RngExt;
+
+		//scaling by a power of two keeps the shape, so the hull must pick the same points
+		for &scale in &[2.0f64.powi(531),2.0f64.powi(1000),2.0f64.powi(-600),2.0f64.powi(-1000)]{
+			for _ in 0..10{
+				let points : Vec<Point3D<f64>> = (0..200).map(|_| p(
+					rand::rng().random_range(-8..8) as f64,
+					rand::rng().random_range(-8..8) as f64,
+					rand::rng().random_range(-8..8) as f64)).collect();
+				let scaled : Vec<Point3D<f64>> = points.iter().map(|q| p(q.x * scale,q.y * scale,q.z * scale)).collect();
+				let hull = quickhull(&points);
+				check_hull(&points,&hull);
+				assert_eq!(quickhull(&scaled).indices,hull.indices);
+			}
+		}
+
+		//around 1e160 the sizes used to pick the start overflow without scaling
+		let points = vec!(p(1e160,0.0,0.0),p(-1e160,0.0,0.0),p(0.0,1e160,0.0),p(0.0,-1e160,0.0),p(0.0,0.0,1e160),p(0.0,0.0,-1e160),p(1e159,1e159,1e159));
+		assert_eq!(quickhull(&points).vertex_indices(),vec!(0,1,2,3,4,5));
+
+		//flat, with the largest coordinate far from one
+		let points = vec!(p(0.0,0.0,1e300),p(1e300,0.0,1e300),p(1e300,1e300,1e300),p(0.0,1e300,1e300),p(5e299,5e299,1e300));
+		assert_eq!(quickhull(&points).vertex_indices(),vec!(0,1,2,3));
+	}
+
+	#[test]
+    fn degenerate_test() {
+		assert_eq!(quickhull::<f64>(&[]).indices.len(),0);
+		assert_eq!(quickhull(&[p(1.0,1.0,1.0),p(1.0,1.0,1.0)]).indices.len(),0);
+		assert_eq!(quickhull(&[p(0.0,0.0,0.0),p(1.0,1.0,1.0),p(2.0,2.0,2.0)]).indices.len(),0);
+
+		//a square in a sloped plane and its middle
+		let points = vec!(p(0.0,0.0,0.0),p(1.0,0.0,1.0),p(1.0,1.0,1.0),p(0.0,1.0,0.0),p(0.5,0.5,0.5),p(f64::NAN,0.0,0.0));
+		let hull = quickhull(&points);
+		assert_eq!(hull.vertex_indices(),vec!(0,1,2,3));
+		assert_eq!(hull.indices.len(),4);
+		assert_eq!(hull.volume(),0.0);
+		assert!((hull.surface_area() - 2.0 * 2.0f64.sqrt()).abs() < 1e-12);
+	}
+}
@@ -0,0 +1,502 @@
+//!Convex hull of a changing set of points.
+
+use std::collections::BTreeMap;
+use crate::primatives2d::{Point2D,TotalPoint2D,TurnDirection};
+use crate::coordinate::FloatCoordinate;
+use crate::algorithms::convex_hull::{HullOptions,apply_options};
+use crate::error::{GeometryError,is_finite};
+
+
+///Convex hull of a set of points that can be added to and removed from.
+///Like the functions in convex_hull the hull starts from the left most point, goes clockwise and leaves out collinear points.
+///
+///The points are kept in a balanced tree ordered by x then y, as in the structure of Overmars and van Leeuwen.
+///Every node stores the bridges, the edges joining the upper and the lower chains of the hulls of its two halves,
+///so a change only has to find the bridges again on the path to the root.
+///Finding a bridge takes O(log² n) time, so inserting and removing take O(log³ n) time in the worst case,
+///however many hull vertices they hide or uncover. contains takes O(log n) time and listing the h hull vertices O(h log n) time.
+#[derive(Clone,Debug,Default)]
+pub struct DynamicHull<T: FloatCoordinate>{
+	points : BTreeMap<TotalPoint2D<T>,usize>,
+	root   : Option<Node<T>>,
+	len    : usize,
+}
+
+impl<T: FloatCoordinate> DynamicHull<T >{
+	///Creates a hull with no points.
+	pub fn new() -> Self{
+		DynamicHull{points: BTreeMap::new(),root: None,len: 0}
+	}
+
+	///Returns the number of points inserted and not removed, counting repeated points every time.
+	pub fn len(&self) -> usize{
+		self.len
+	}
+
+	///Returns true if there are no points.
+	pub fn is_empty(&self) -> bool{
+		self.len == 0
+	}
+
+	///Adds a point. Points with a NaN or infinite coordinate are ignored, use try_insert to find out about them.
+	pub fn insert(&mut self, point: Point2D<T>){
+		let _ = self.try_insert(point);
+	}
+
+	///Adds a point, or returns an error if it has a NaN or infinite coordinate and leaves the hull unchanged.
+	pub fn try_insert(&mut self, point: Point2D<T>) -> Result<(),GeometryError>{
+		if !is_finite(&point){
+			return Err(GeometryError::NonFiniteCoordinate(0));
+		}
+		let key = TotalPoint2D::new(point);
+
+		self.len += 1;
+		let count = self.points.entry(key).or_insert(0);
+		*count += 1;
+		if *count == 1{
+			self.root = Some(match self.root.take(){
+				Some(root) => root.insert(key),
+				None => Node::Leaf(key),
+			});
+		}
+		Ok(())
+	}
+
+	///Removes one copy of a point, returns false if the point was not in the set.
+	pub fn remove(&mut self, point: &Point2D<T>) -> bool{
+		let key = TotalPoint2D::new(*point);
+		match self.points.get_mut(&key){
+			None => return false,
+			Some(count) if *count > 1 => {
+				*count -= 1;
+				self.len -= 1;
+				return true;
+			},
+			Some(_) => {},
+		}
+		self.points.remove(&key);
+		self.len -= 1;
+
+		self.root = self.root.take().and_then(|root| root.remove(&key));
+		true
+	}
+
+	///Returns true if the point is inside the hull or on its boundary.
+	pub fn contains(&self, point: &Point2D<T>) -> bool{
+		if point.x.is_nan() || point.y.is_nan(){
+			return false;
+		}
+		let key = TotalPoint2D::new(*point);
+		match &self.root{
+			None => false,
+			Some(root) => chain_contains(root,key,TurnDirection::RightTurn) && chain_contains(root,key,TurnDirection::LeftTurn),
+		}
+	}
+
+	///Returns the vertices of the hull, starting from the left most point and going clockwise.
+	pub fn hull(&self) -> impl Iterator<Item = Point2D<T>> + '_{
+		let mut upper = Vec::new();
+		let mut lower = Vec::new();
+		if let Some(root) = &self.root{
+			collect_chain(root,None,None,TurnDirection::RightTurn,&mut upper);
+			collect_chain(root,None,None,TurnDirection::LeftTurn,&mut lower);
+		}
+
+		//the lower chain shares its end points with the upper chain
+		let lower_inner = lower.len().saturating_sub(2);
+		upper.into_iter().chain(lower.into_iter().rev().skip(1).take(lower_inner))
+	}
+
+	///Returns the vertices of the hull with the given options.
+	///Including the collinear points looks at every point, so takes O(n log n) time.
+	pub fn hull_with_options(&self, options: &HullOptions) -> Vec<Point2D<T>>{
+		let hull = self.hull().collect();
+		if options.include_collinear{
+			let points : Vec<Point2D<T>> = self.points.keys().map(|key| key.point()).collect();
+			apply_options(hull,&points,options)
+		}
+		else{
+			apply_options(hull,&[],options)
+		}
+	}
+}
+
+//Tree of distinct points ordered by x then y, a leaf holds one point.
+#[derive(Clone,Debug)]
+enum Node<T: FloatCoordinate>{
+	Leaf(TotalPoint2D<T>),
+	Branch(Box<Branch<T>>),
+}
+
+//Every point of left comes before every point of right. The bridges are the edges from a point of left
+//to a point of right of the upper chain, where every 3 consecutive points turn right, and of the lower chain,
+//where they turn left.
+#[derive(Clone,Debug)]
+struct Branch<T: FloatCoordinate>{
+	left   : Node<T>,
+	right  : Node<T>,
+	last   : TotalPoint2D<T>,
+	height : usize,
+	upper  : (TotalPoint2D<T>,TotalPoint2D<T>),
+	lower  : (TotalPoint2D<T>,TotalPoint2D<T>),
+}
+
+impl<T: FloatCoordinate> Branch<T >{
+	//Returns the bridge of the chain turning in 'turn' direction.
+	fn bridge(&self, turn: TurnDirection) -> (TotalPoint2D<T>,TotalPoint2D<T>){
+		if turn == TurnDirection::RightTurn {self.upper} else {self.lower}
+	}
+}
+
+impl<T: FloatCoordinate> Node<T >{
+	fn last(&self) -> TotalPoint2D<T>{
+		match self{
+			Node::Leaf(key) => *key,
+			Node::Branch(branch) => branch.last,
+		}
+	}
+
+	fn height(&self) -> usize{
+		match self{
+			Node::Leaf(_) => 1,
+			Node::Branch(branch) => branch.height,
+		}
+	}
+
+	//Joins two trees where every point of left comes before every point of right.
+	fn join(left: Node<T>, right: Node<T>) -> Node<T>{
+		let upper = find_bridge(&left,&right,TurnDirection::RightTurn);
+		let lower = find_bridge(&left,&right,TurnDirection::LeftTurn);
+		Node::Branch(Box::new(Branch{
+			last: right.last(),
+			height: 1 + left.height().max(right.height()),
+			left,
+			right,
+			upper,
+			lower,
+		}))
+	}
+
+	//Joins two trees whose heights differ by at most 2, rotating them so the heights of the halves differ by at most 1.
+	fn balance(left: Node<T>, right: Node<T>) -> Node<T>{
+		match (left,right){
+			(Node::Branch(left),right) if left.height > right.height() + 1 => {
+				let Branch{left: left_left,right: left_right,..} = *left;
+				match left_right{
+					Node::Branch(middle) if middle.height > left_left.height() => {
+						let Branch{left: middle_left,right: middle_right,..} = *middle;
+						Node::join(Node::join(left_left,middle_left),Node::join(middle_right,right))
+					},
+					left_right => Node::join(left_left,Node::join(left_right,right)),
+				}
+			},
+			(left,Node::Branch(right)) if right.height > left.height() + 1 => {
+				let Branch{left: right_left,right: right_right,..} = *right;
+				match right_left{
+					Node::Branch(middle) if middle.height > right_right.height() => {
+						let Branch{left: middle_left,right: middle_right,..} = *middle;
+						Node::join(Node::join(left,middle_left),Node::join(middle_right,right_right))
+					},
+					right_left => Node::join(Node::join(left,right_left),right_right),
+				}
+			},
+			(left,right) => Node::join(left,right),
+		}
+	}
+
+	//Adds a point that is not in the tree.
+	fn insert(self, key: TotalPoint2D<T>) -> Node<T>{
+		match self{
+			Node::Leaf(point) if key < point => Node::join(Node::Leaf(key),Node::Leaf(point)),
+			Node::Leaf(point) => Node::join(Node::Leaf(point),Node::Leaf(key)),
+			Node::Branch(branch) => {
+				let Branch{left,right,..} = *branch;
+				if key <= left.last(){
+					Node::balance(left.insert(key),right)
+				}
+				else{
+					Node::balance(left,right.insert(key))
+				}
+			},
+		}
+	}
+
+	//Removes a point that is in the tree, returns None if it was the only one.
+	fn remove(self, key: &TotalPoint2D<T>) -> Option<Node<T>>{
+		match self{
+			Node::Leaf(_) => None,
+			Node::Branch(branch) => {
+				let Branch{left,right,..} = *branch;
+				if *key <= left.last(){
+					Some(match left.remove(key){
+						Some(left) => Node::balance(left,right),
+						None => right,
+					})
+				}
+				else{
+					Some(match right.remove(key){
+						Some(right) => Node::balance(left,right),
+						None => left,
+					})
+				}
+			},
+		}
+	}
+}
+
+//Returns the first point of the chain of 'node' on the line touching the chain from 'point', which comes after all of its points.
+fn tangent<T: FloatCoordinate>(mut node: &Node<T>, point: &Point2D<T>, turn: TurnDirection) -> TotalPoint2D<T>{
+	loop{
+		match node{
+			Node::Leaf(key) => return *key,
+			Node::Branch(branch) => {
+				//the line from 'point' touches the chain before the bridge if 'point' is outside the line through the bridge
+				let (a,b) = branch.bridge(turn);
+				node = if a.point().turn_direction(&b.point(),point) != turn {&branch.left} else {&branch.right};
+			},
+		}
+	}
+}
+
+//Returns the edge from a point of left to a point of right of the chain of both, taking the outer most points if several are collinear.
+//Every step down right finds a tangent to left, so this takes O(log² n) time.
+fn find_bridge<T: FloatCoordinate>(left: &Node<T>, mut right: &Node<T>, turn: TurnDirection) -> (TotalPoint2D<T>,TotalPoint2D<T>){
+	loop{
+		match right{
+			Node::Leaf(key) => return (tangent(left,&key.point(),turn),*key),
+			Node::Branch(branch) => {
+				//the bridge ends after the edge (c,d) if some point of left is outside the line through it
+				let (c,d) = branch.bridge(turn);
+				let touching = tangent(left,&d.point(),turn);
+				right = if c.point().turn_direction(&d.point(),&touching.point()) != turn {&branch.right} else {&branch.left};
+			},
+		}
+	}
+}
+
+//Adds the points of the chain of 'node' between 'from' and 'to' to 'chain', in order.
+fn collect_chain<T: FloatCoordinate>(node: &Node<T>, from: Option<TotalPoint2D<T>>, to: Option<TotalPoint2D<T>>, turn: TurnDirection, chain: &mut Vec<Point2D<T>>){
+	match node{
+		Node::Leaf(key) => {
+			if from.is_none_or(|from| from <= *key) && to.is_none_or(|to| *key <= to){
+				chain.push(key.point());
+			}
+		},
+		Node::Branch(branch) => {
+			//the chain follows left up to the bridge and right after it
+			let (a,b) = branch.bridge(turn);
+			if from.is_none_or(|from| from <= a){
+				collect_chain(&branch.left,from,Some(to.map_or(a,|to| to.min(a))),turn,chain);
+			}
+			if to.is_none_or(|to| b <= to){
+				collect_chain(&branch.right,Some(from.map_or(b,|from| from.max(b))),to,turn,chain);
+			}
+		},
+	}
+}
+
+//Returns true if the point is on the inner side of the chain of 'node', or on it.
+fn chain_contains<T: FloatCoordinate>(mut node: &Node<T>, key: TotalPoint2D<T>, turn: TurnDirection) -> bool{
+	loop{
+		match node{
+			Node::Leaf(point) => return *point == key,
+			Node::Branch(branch) => {
+				let (a,b) = branch.bridge(turn);
+				if key < a{
+					node = &branch.left;
+				}
+				else if b < key{
+					node = &branch.right;
+				}
+				else{
+					return a.point().turn_direction(&key.point(),&b.point()) != turn;
+				}
+			},
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod dynamic_hull_test {
+	use super::*;
+	use crate::algorithms::convex_hull::{quickhull,monotone_chain};
+
+	fn p(x: f64,y: f64) -> Point2D<f64>{
+		Point2D::new(x,y)
+	}
+
+	#[test]
+    fn insert_test() {
+		let mut hull = DynamicHull::new();
+		assert!(hull.is_empty());
+		assert_eq!(hull.hull().count(),0);
+		assert!(!hull.contains(&p(0.0,0.0)));
+
+		hull.insert(p(0.0,0.0));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0)));
+		hull.insert(p(1.0,1.0));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0),p(1.0,1.0)));
+		hull.insert(p(0.5,0.5));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0),p(1.0,1.0)));
+
+		hull.insert(p(1.0,0.0));
+		hull.insert(p(0.0,1.0));
+		hull.insert(p(0.0,1.0));
+		hull.insert(p(f64::NAN,1.0));
+		assert_eq!(hull.len(),6);
+		assert_eq!(hull.try_insert(p(f64::NAN,1.0)),Err(GeometryError::NonFiniteCoordinate(0)));
+		assert_eq!(hull.try_insert(p(0.0,f64::INFINITY)),Err(GeometryError::NonFiniteCoordinate(0)));
+		assert_eq!(hull.try_insert(p(0.5,0.5)),Ok(()));
+		assert_eq!(hull.len(),7);
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0),p(0.0,1.0),p(1.0,1.0),p(1.0,0.0)));
+	}
+
+	#[test]
+    fn contains_test() {
+		let mut hull = DynamicHull::new();
+		for &(x,y) in &[(0.0,0.0),(0.0,1.0),(1.0,1.0),(1.0,0.0)]{
+			hull.insert(p(x,y));
+		}
+
+		assert!(hull.contains(&p(0.5,0.5)));
+		assert!(hull.contains(&p(0.0,0.5)));
+		assert!(hull.contains(&p(1.0,0.5)));
+		assert!(hull.contains(&p(0.5,1.0)));
+		assert!(hull.contains(&p(1.0,1.0)));
+		assert!(!hull.contains(&p(0.0,-0.5)));
+		assert!(!hull.contains(&p(0.5,1.5)));
+		assert!(!hull.contains(&p(1.5,0.5)));
+		assert!(!hull.contains(&p(f64::NAN,0.5)));
+	}
+
+	#[test]
+    fn remove_test() {
+		let mut hull = DynamicHull::new();
+		for &(x,y) in &[(0.0,0.0),(0.0,2.0),(2.0,2.0),(2.0,0.0),(1.0,1.0),(1.0,0.5),(2.0,0.0)]{
+			hull.insert(p(x,y));
+		}
+
+		assert!(!hull.remove(&p(5.0,5.0)));
+		assert!(hull.remove(&p(1.0,1.0)));
+		assert!(hull.remove(&p(2.0,0.0)));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0),p(0.0,2.0),p(2.0,2.0),p(2.0,0.0)));
+
+		assert!(hull.remove(&p(2.0,0.0)));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,0.0),p(0.0,2.0),p(2.0,2.0),p(1.0,0.5)));
+		assert!(!hull.contains(&p(2.0,0.0)));
+
+		assert!(hull.remove(&p(0.0,0.0)));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(0.0,2.0),p(2.0,2.0),p(1.0,0.5)));
+		assert!(hull.remove(&p(0.0,2.0)));
+		assert!(hull.remove(&p(2.0,2.0)));
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(1.0,0.5)));
+		assert!(hull.remove(&p(1.0,0.5)));
+		assert!(hull.is_empty());
+		assert_eq!(hull.hull().count(),0);
+	}
+
+	#[test]
+    fn random_test() {
+		use rand::RngExt;
+
+		let mut hull = DynamicHull::new();
+		let mut points = Vec::new();
+		for i in 0..2000{
+			let point = p(rand::rng().random_range(0..40) as f64,rand::rng().random_range(0..40) as f64);
+			hull.insert(point);
+			points.push(point);
+
+			if i % 3 == 1{
+				let removed = points.swap_remove(rand::rng().random_range(0..points.len()));
+				assert!(hull.remove(&removed));
+			}
+			if i % 50 == 0{
+				assert_eq!(hull.hull().collect::<Vec<_>>(),quickhull(&points));
+				let query = p(rand::rng().random_range(-1..41) as f64,rand::rng().random_range(-1..41) as f64);
+				let mut with_query = points.clone();
+				with_query.push(query);
+				assert_eq!(hull.contains(&query),quickhull(&with_query) == quickhull(&points));
+			}
+		}
+	}
+
+	//the height of an AVL tree with n leaves is at most 1.44 log2(n + 2)
+	fn assert_balanced(hull: &DynamicHull<f64>){
+		let height = hull.root.as_ref().map_or(0,|root| root.height());
+		assert!(height as f64 <= 1.45*((hull.points.len() + 2) as f64).log2() + 1.0);
+	}
+
+	#[test]
+    fn remove_hull_vertices_test() {
+		use rand::RngExt;
+
+		//points on a circle are all hull vertices, the grid inside is uncovered as they go
+		let mut points = Vec::new();
+		for i in 0..1000{
+			let angle = (i as f64)*std::f64::consts::PI/500.0;
+			points.push(p(100.0*angle.cos(),100.0*angle.sin()));
+		}
+		for x in -20..20{
+			for y in -20..20{
+				points.push(p((x*3) as f64,(y*3) as f64));
+			}
+		}
+		let mut hull = DynamicHull::new();
+		for &point in &points{
+			hull.insert(point);
+		}
+		assert_balanced(&hull);
+
+		let mut circle_left = 1000;
+		while points.len() > 1{
+			let index = if circle_left > 0 {rand::rng().random_range(0..circle_left)} else {rand::rng().random_range(0..points.len())};
+			let removed = points.remove(index);
+			circle_left = circle_left.saturating_sub(1);
+			assert!(hull.remove(&removed));
+			if points.len() % 97 == 0 || points.len() < 10{
+				assert_eq!(hull.hull().collect::<Vec<_>>(),monotone_chain(&mut points.clone()));
+				assert_balanced(&hull);
+			}
+		}
+	}
+
+	#[test]
+    fn stacked_points_test() {
+		//every removal of the top point uncovers the next one below it
+		let mut hull = DynamicHull::new();
+		hull.insert(p(-1.0,0.0));
+		hull.insert(p(1.0,0.0));
+		for i in 0..500{
+			hull.insert(p(0.0,i as f64));
+		}
+		assert_balanced(&hull);
+		for i in (1..500).rev(){
+			assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(-1.0,0.0),p(0.0,i as f64),p(1.0,0.0)));
+			assert!(hull.remove(&p(0.0,i as f64)));
+		}
+		assert_eq!(hull.hull().collect::<Vec<_>>(),vec!(p(-1.0,0.0),p(1.0,0.0)));
+	}
+
+	#[test]
+    fn random_float_test() {
+		use rand::RngExt;
+
+		let mut hull = DynamicHull::new();
+		let mut points = Vec::new();
+		for i in 0..3000{
+			let point = p(rand::rng().random(),rand::rng().random());
+			hull.insert(point);
+			points.push(point);
+
+			if i % 2 == 1{
+				let removed = points.swap_remove(rand::rng().random_range(0..points.len()));
+				assert!(hull.remove(&removed));
+			}
+			if i % 100 == 0{
+				assert_eq!(hull.hull().collect::<Vec<_>>(),monotone_chain(&mut points.clone()));
+				assert_balanced(&hull);
+			}
+		}
+	}
+}
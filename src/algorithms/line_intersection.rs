@@ -1,100 +1,168 @@
 //!Algorithms for calculating intersection points of line sets.
 
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-use crate::primatives2d::{Point2D,Line2D};
-use num_traits::{Float,Zero};
+use std::collections::BTreeMap;
+use crate::primatives2d::{Point2D,TotalPoint2D,Line2D,SegmentIntersection,TurnDirection};
+use crate::coordinate::FloatCoordinate;
+use crate::point2d_like::{Point2DLike,Segment2DLike};
+
+///Point where segments cross, with the indices of the crossing segments in the input in increasing order.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Crossing<T: FloatCoordinate>{
+	///Intersection point
+	pub point : Point2D<T>,
+	///Indices of the segments found crossing at the point
+	pub segments : Vec<usize>,
+}
 
 ///Preforms the Bentley Ottmann Line Intersection Algorithm on a set of lines.
-pub fn bentley_ottmann<T:Float+Zero>(lines : &[Line2D<T>]) -> Vec<Point2D<T>>  {
+///Lines can be Line2Ds or any Segment2DLike type, such as pairs of a Point2DLike type.
+///Every intersection point is returned once, with the indices of the lines crossing there, ordered by x then y.
+///Touching segments intersect at the touching point, collinear overlapping segments at both ends of the shared part.
+///Crossing points that are not representable are rounded, segments passing within rounding error of one are reported with it.
+pub fn bentley_ottmann<T,S>(lines : &[S]) -> Vec<Crossing<T>> where
+	T : FloatCoordinate,
+	S : Segment2DLike,
+	S::Point : Point2DLike<Coordinate = T>{
 	//https://en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+	//Degenerate cases are handled as in de Berg et al. "Computational Geometry", chapter 2:
+	//the sweep line moves along x, segments through an event point are removed and reinserted in their order after it.
 
-	let mut heap = BinaryHeap::new();
-	let mut tree = Vec::new();
-	let mut result = Vec::new();
-
-	for &l in lines{
-		heap.push(BentleyOttmannEvent{event_point: l.p1,event_type: BentleyOttmannEventType::LeftPointEvent(l) });
+	//segments with p1 before p2 in sweep order
+	let segments : Vec<Line2D<T>> = lines.iter().map(|l| {
+		let (start,end) = (l.start().to_point(),l.end().to_point());
+		if TotalPoint2D::new(start) <= TotalPoint2D::new(end) {Line2D::new(start,end)} else {Line2D::new(end,start)}
+	}).collect();
 
+	let mut events : BTreeMap<TotalPoint2D<T>,BentleyOttmannEvent> = BTreeMap::new();
+	for (index,segment) in segments.iter().enumerate(){
+		let start = events.entry(TotalPoint2D::new(segment.p1)).or_default();
+		start.endpoint = true;
+		start.segments.push(index);
+		events.entry(TotalPoint2D::new(segment.p2)).or_default().endpoint = true;
 	}
 
-	while let Some(event) = heap.pop(){
-		match  event.event_type {
-			BentleyOttmannEventType::LeftPointEvent(line) =>{
-				let pos = tree.binary_search(&line).unwrap_or_else(|e| e);
-				tree.insert(pos, line);
-				if pos != 0 {
-					if let Some(&left_line) = tree.get(pos-1) {
-						if let Some(p) = line.intersection_point(&left_line) {
-							heap.push(BentleyOttmannEvent { event_point: p, event_type: BentleyOttmannEventType::CrossLineEvent(left_line, line) });
-						}
-					}
-				}
-				if let Some(&right_line) = tree.get(pos+1){
-					if let Some(p) = line.intersection_point(&right_line){
-						heap.push(BentleyOttmannEvent{event_point: p,event_type: BentleyOttmannEventType::CrossLineEvent(line,right_line)});
+	//segments crossing the sweep line, from bottom to top
+	let mut status : Vec<usize> = Vec::new();
+	let mut active = vec![false;segments.len()];
+	let mut result : Vec<Crossing<T>> = Vec::new();
 
-					}
-				}
+	//Returns true if segment 'below' has to pass segment 'above' further along the sweep.
+	let converging = |below: usize,above: usize| {
+		let (b,a) = (&segments[below],&segments[above]);
+		T::cross_sign(&b.p1.x,&b.p1.y,&b.p2.x,&b.p2.y,&a.p1.x,&a.p1.y,&a.p2.x,&a.p2.y) == Ordering::Less
+	};
 
-			},
-			BentleyOttmannEventType::RightPointEvent(line) =>{
-				let pos = tree.binary_search(&line).unwrap_or_else(|e| e);
-				tree.remove(pos);
-				if let Some(&left_line) = tree.get(pos-1){
-					if let Some(&right_line) = tree.get(pos){
-						if let Some(p) = line.intersection_point(&left_line){
-							heap.push(BentleyOttmannEvent{event_point: p,event_type: BentleyOttmannEventType::CrossLineEvent(left_line,right_line)});
+	while let Some((event,BentleyOttmannEvent{endpoint,segments: known})) = events.pop_first(){
+		let point = event.point();
+		//which side of the point a segment in the status passes, Equal if it passes through it
+		let side = |index: &usize| {
+			if known.contains(index){
+				return Ordering::Equal;
+			}
+			match segments[*index].p1.turn_direction(&segments[*index].p2,&point){
+				TurnDirection::LeftTurn => Ordering::Less,
+				TurnDirection::RightTurn => Ordering::Greater,
+				TurnDirection::NoTurn => Ordering::Equal,
+			}
+		};
 
-						}
-					}
-				}
-			},
-			BentleyOttmannEventType::CrossLineEvent(left_line,right_line) =>{
-				result.push(event.event_point);
+		//Crossing points are rounded, so only end points, which are exact, are tested against the segments in the status.
+		//At a crossing the segments known to cross there are used.
+		let mut through : Vec<usize> = known.iter().cloned().filter(|&index| active[index] || TotalPoint2D::new(segments[index].p1) == event).collect();
+		if endpoint{
+			let low = status.partition_point(|index| side(index) == Ordering::Less);
+			let high = low + status[low..].partition_point(|index| side(index) == Ordering::Equal);
+			through.extend(&status[low..high]);
+		}
 
-			},
+		//Neighbours whose crossing rounds to the point or before it also pass through it.
+		//They are added until the order after the point is consistent.
+		let (position,count) = loop{
+			//segments between two segments through the point pass through it too
+			let first = status.iter().position(|index| through.contains(index));
+			let last = status.iter().rposition(|index| through.contains(index));
+			if let (Some(first),Some(last)) = (first,last){
+				through.extend(&status[first..=last]);
+			}
+			through.sort_unstable();
+			through.dedup();
+			status.retain(|index| !through.contains(index));
 
-		}
-	}
+			let position = if endpoint {status.partition_point(|index| side(index) == Ordering::Less)} else {first.unwrap_or(status.len())};
 
+			//segments continuing past the point, ordered by their direction
+			let mut continuing : Vec<usize> = through.iter().cloned().filter(|&index| TotalPoint2D::new(segments[index].p2) > event).collect();
+			continuing.sort_by(|&a,&b| {
+				let (a_line,b_line) = (&segments[a],&segments[b]);
+				T::cross_sign(&a_line.p1.x,&a_line.p1.y,&a_line.p2.x,&a_line.p2.y,&b_line.p1.x,&b_line.p1.y,&b_line.p2.x,&b_line.p2.y)
+					.reverse().then(a.cmp(&b))
+			});
+			let count = continuing.len();
+			status.splice(position..position,continuing);
 
-	result
-}
+			let missed : Vec<usize> = neighbours(&status,position,count).into_iter().filter(|&(below,above)| {
+				crossing_point(&segments[below],&segments[above]).is_some_and(|p| p <= event) && converging(below,above)
+			}).flat_map(|(below,above)| vec!(below,above)).collect();
 
+			if missed.iter().all(|index| through.contains(index)){
+				break (position,count);
+			}
+			through.extend(missed);
+		};
 
+		for &index in &through{
+			active[index] = TotalPoint2D::new(segments[index].p2) > event;
+		}
+		if through.len() > 1{
+			result.push(Crossing{point,segments: through});
+		}
 
-struct  BentleyOttmannEvent<T: Float+Zero>{
+		for (below,above) in neighbours(&status,position,count){
+			if let Some(p) = crossing_point(&segments[below],&segments[above]){
+				if p > event && converging(below,above){
+					events.entry(p).or_default().segments.extend(&[below,above]);
+				}
+			}
+		}
+	}
 
-	event_point :  Point2D<T>,
-	event_type : BentleyOttmannEventType<T>
-}
-impl<T:Float+Zero> Ord for BentleyOttmannEvent<T> {
-    fn cmp(&self, other: &BentleyOttmannEvent<T>) -> Ordering {
-       self.partial_cmp(other).unwrap()
-    }
+	result
 }
 
-impl<T:Float+Zero> PartialOrd for BentleyOttmannEvent<T> {
-    fn partial_cmp(&self, other: &BentleyOttmannEvent<T>) -> Option<Ordering> {
-         self.event_point.x_then_y_partial_cmp(&other.event_point )
-    }
+///Returns the point where segments 'a' and 'b', with p1 before p2 in sweep order, meet in a single point.
+///The rounded point is moved back into both segments' bounds, so a crossing on a vertical segment or next to an end point stays on it.
+fn crossing_point<T: FloatCoordinate>(a: &Line2D<T>, b: &Line2D<T>) -> Option<TotalPoint2D<T>>{
+	match a.intersection(b){
+		SegmentIntersection::Point(p) => {
+			let x = p.x.max(a.p1.x).max(b.p1.x).min(a.p2.x).min(b.p2.x);
+			let y = p.y.max(a.p1.y.min(a.p2.y)).max(b.p1.y.min(b.p2.y)).min(a.p1.y.max(a.p2.y)).min(b.p1.y.max(b.p2.y));
+			let p = TotalPoint2D::new(Point2D::new(x,y));
+			Some(p.min(TotalPoint2D::new(a.p2)).min(TotalPoint2D::new(b.p2)))
+		},
+		_ => None,
+	}
 }
 
-impl<T:Float+Zero> PartialEq for BentleyOttmannEvent<T> {
-    fn eq(&self, other: &BentleyOttmannEvent<T>) -> bool {
-        self.event_point == other.event_point &&
-			self.event_type == other.event_type
-    }
-}
-impl<T:Float+Zero> Eq for BentleyOttmannEvent<T> {
+///Returns the pairs of neighbouring segments around 'count' segments inserted at 'position' in the status.
+///Without inserted segments this is the pair around the position.
+fn neighbours(status: &[usize], position: usize, count: usize) -> Vec<(usize,usize)>{
+	let mut pairs = Vec::new();
+	if position > 0 && position < status.len(){
+		pairs.push((status[position - 1],status[position]));
+	}
+	if count > 0 && position + count < status.len(){
+		pairs.push((status[position + count - 1],status[position + count]));
+	}
+	pairs
 }
 
-#[derive(Eq,PartialEq )]
-enum BentleyOttmannEventType<T: Float+Zero> {
-	LeftPointEvent(Line2D<T>),
-	RightPointEvent(Line2D<T>),
-	CrossLineEvent(Line2D<T>,Line2D<T>),
+//Event point with the segments known to pass through it, the ones starting there and the crossing ones.
+#[derive(Default)]
+struct BentleyOttmannEvent{
+	//true if the point is an end point of a segment, and so exact
+	endpoint : bool,
+	segments : Vec<usize>,
 }
 
 
@@ -109,11 +177,146 @@ mod algorithms_test {
 			Line2D::new(Point2D::new(2.0,0.0),Point2D::new(0.0,2.0)),
 		);
 		let expected_results = vec!(
-			Point2D::new(1.0,1.0),
+			Crossing{point: Point2D::new(1.0,1.0),segments: vec!(0,1)},
 		);    
 
 		let result = bentley_ottmann(&lines);
 		assert_eq!(result,expected_results);
-	}    
+	}
+
+    #[test]
+    fn bentley_ottmann_on_segments_test() {
+		use crate::tolerance::Tolerance;
+
+        let lines = vec!(
+			Line2D::new(Point2D::new(0.1,0.3),Point2D::new(7.7,3.9)),
+			Line2D::new(Point2D::new(0.2,5.3),Point2D::new(6.1,0.7)),
+		);
+		let tolerance = Tolerance::default();
+
+		let result = bentley_ottmann(&lines);
+		assert_eq!(result.len(),1);
+		assert!(lines.iter().all(|line| line.contains_point_with_tolerance(&result[0].point,&tolerance)));
+		assert_eq!(result[0].segments,vec!(0,1));
+	}
+
+    #[test]
+    fn bentley_ottmann_duplicate_test() {
+		//three lines crossing at the same point
+        let lines = vec!(
+			Line2D::new(Point2D::new(0.0,0.0),Point2D::new(2.0,2.0)),
+			Line2D::new(Point2D::new(0.0,1.0),Point2D::new(2.0,1.0)),
+			Line2D::new(Point2D::new(2.0,0.0),Point2D::new(0.0,2.0)),
+		);
+
+		let result = bentley_ottmann(&lines);
+		assert_eq!(result,vec!(Crossing{point: Point2D::new(1.0,1.0),segments: vec!(0,1,2)}));
+	}
+
+    #[test]
+    fn bentley_ottmann_point_pairs_test() {
+		let lines = vec!(
+			((5.0,5.0),(6.0,5.0)),
+			((0.0,0.0),(2.0,2.0)),
+			((2.0,0.0),(0.0,2.0)),
+		);
+		assert_eq!(bentley_ottmann(&lines),vec!(Crossing{point: Point2D::new(1.0,1.0),segments: vec!(1,2)}));
+
+		//segments of a type that can not be copied, passed by reference
+		let lines : Vec<[Box<(f64,f64)>;2]> = vec!(
+			[Box::new((0.0,0.0)),Box::new((2.0,2.0))],
+			[Box::new((2.0,0.0)),Box::new((0.0,2.0))],
+		);
+		let references : Vec<&[Box<(f64,f64)>;2]> = lines.iter().collect();
+		assert_eq!(bentley_ottmann(&references),vec!(Crossing{point: Point2D::new(1.0,1.0),segments: vec!(0,1)}));
+	}
+
+
+    #[test]
+    fn bentley_ottmann_multiple_crossings_test() {
+		let p = |x,y| Point2D::new(x,y);
+		//a horizontal segment crossed by three verticals
+		let lines = vec!(
+			(p(0.0,0.0),p(10.0,0.0)),
+			(p(1.0,-1.0),p(1.0,1.0)),
+			(p(2.0,-1.0),p(2.0,1.0)),
+			(p(3.0,1.0),p(3.0,-1.0)),
+		);
+		assert_eq!(bentley_ottmann(&lines),vec!(
+			Crossing{point: p(1.0,0.0),segments: vec!(0,1)},
+			Crossing{point: p(2.0,0.0),segments: vec!(0,2)},
+			Crossing{point: p(3.0,0.0),segments: vec!(0,3)},
+		));
+
+		//segments swapping order twice
+		let lines = vec!(
+			(p(0.0,0.0),p(4.0,4.0)),
+			(p(0.0,4.0),p(4.0,0.0)),
+			(p(0.0,1.0),p(4.0,1.0)),
+		);
+		assert_eq!(bentley_ottmann(&lines),vec!(
+			Crossing{point: p(1.0,1.0),segments: vec!(0,2)},
+			Crossing{point: p(2.0,2.0),segments: vec!(0,1)},
+			Crossing{point: p(3.0,1.0),segments: vec!(1,2)},
+		));
+	}
+
+    #[test]
+    fn bentley_ottmann_overlap_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let lines = vec!(
+			(p(0.0,0.0),p(4.0,0.0)),
+			(p(1.0,0.0),p(2.0,0.0)),
+		);
+		assert_eq!(bentley_ottmann(&lines),vec!(
+			Crossing{point: p(1.0,0.0),segments: vec!(0,1)},
+			Crossing{point: p(2.0,0.0),segments: vec!(0,1)},
+		));
+
+		//a vertical overlap and a segment touching it
+		let lines = vec!(
+			(p(0.0,3.0),p(0.0,0.0)),
+			(p(0.0,1.0),p(0.0,5.0)),
+			(p(0.0,2.0),p(1.0,2.0)),
+		);
+		assert_eq!(bentley_ottmann(&lines),vec!(
+			Crossing{point: p(0.0,1.0),segments: vec!(0,1)},
+			Crossing{point: p(0.0,2.0),segments: vec!(0,1,2)},
+			Crossing{point: p(0.0,3.0),segments: vec!(0,1)},
+		));
+	}
+
+    #[test]
+    fn bentley_ottmann_random_test() {
+		use rand::RngExt;
+		use std::collections::BTreeSet;
+
+		//segments on a small grid, with many shared end points, collinear overlaps and vertical segments
+		for _ in 0..50{
+			let coordinate = || rand::rng().random_range(0..8) as f64;
+			let lines : Vec<Line2D<f64>> = (0..20).map(|_| Line2D::new(Point2D::new(coordinate(),coordinate()),Point2D::new(coordinate(),coordinate()))).collect();
+
+			let mut expected = BTreeSet::new();
+			for i in 0..lines.len(){
+				for j in i + 1..lines.len(){
+					if lines[i].intersection(&lines[j]) != SegmentIntersection::None{
+						expected.insert((i,j));
+					}
+				}
+			}
+
+			let mut found = BTreeSet::new();
+			let result = bentley_ottmann(&lines);
+			for crossing in &result{
+				for (n,&i) in crossing.segments.iter().enumerate(){
+					for &j in &crossing.segments[n + 1..]{
+						found.insert((i,j));
+					}
+				}
+			}
+			assert_eq!(found,expected,"{:?}",lines);
+			assert!(result.windows(2).all(|w| TotalPoint2D::new(w[0].point) < TotalPoint2D::new(w[1].point)));
+		}
+	}
 
 }
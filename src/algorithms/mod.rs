@@ -1,5 +1,7 @@
 //!Algorithms
 
 pub mod convex_hull;
+pub mod convex_hull_3d;
+pub mod dynamic_hull;
 pub mod line_intersection;
 pub mod polygon_area;
\ No newline at end of file
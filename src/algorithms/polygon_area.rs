@@ -1,28 +1,93 @@
 //!Algorithms for calculating area of a set of points for a polygon.
 
-use crate::primatives2d::{Point2D};
-use num_traits::{Float,Zero};
-use std::ops::Deref;
+use crate::point2d_like::Point2DLike;
+use crate::error::{GeometryError,is_finite};
+use num_traits::{Zero,One};
 
 ///Preforms the Shoelace/Gauss Area Algorithm on a iterator of points to calculate area.
-pub fn shoelace<'a,T:Float+Zero+'a,I,P>(mut points : I ) -> T where
+///For integer coordinates the halving truncates, use signed_shoelace_doubled for an exact result.
+pub fn shoelace<I,P>(points : I ) -> P::Coordinate where
 	I : Iterator<Item = P>,
-	P : Deref<Target = Point2D<T>> + 'a{
+	P : Point2DLike{
+
+	let area = signed_shoelace(points);
+	if area < P::Coordinate::zero(){
+		-area
+	}
+	else{
+		area
+	}
+}
+
+///Preforms the Shoelace/Gauss Area Algorithm on a iterator of points to calculate signed area.
+///The area is positive for counter-clockwise polygons and negative for clockwise ones.
+pub fn signed_shoelace<I,P>(points : I ) -> P::Coordinate where
+	I : Iterator<Item = P>,
+	P : Point2DLike{
+
+	signed_shoelace_doubled(points) / (P::Coordinate::one() + P::Coordinate::one())
+}
+
+///Returns twice the signed area of the polygon, counter-clockwise positive.
+///No division is performed, so the result is exact for integer coordinates.
+///Panics if there are no points, see try_signed_shoelace_doubled.
+pub fn signed_shoelace_doubled<I,P>(mut points : I ) -> P::Coordinate where
+	I : Iterator<Item = P>,
+	P : Point2DLike{
 	//https://en.wikipedia.org/wiki/Shoelace_formula
 	
-	let mut last_point = *points.next().unwrap();
-	let first_point = last_point;
+	let first_point = points.next().unwrap().to_point();
+	let mut last_point = first_point.clone();
 	
-	let mut area = T::zero();
+	let mut area = P::Coordinate::zero();
 	for point in points
 	{
-		area =area+ (point.x + last_point.x)*(point.y - last_point.y);
-		last_point = *point;
+		let point = point.to_point();
+		area = area + (point.x.clone() + last_point.x.clone())*(point.y.clone() - last_point.y.clone());
+		last_point = point;
 	}
-	area =area+ (first_point.x + last_point.x)*(first_point.y - last_point.y);
+	area = area + (first_point.x + last_point.x)*(first_point.y - last_point.y);
+
+	area
+}
+
+///Like shoelace, but returns an error for no points or a NaN or infinite coordinate instead of panicking.
+pub fn try_shoelace<I,P>(points : I ) -> Result<P::Coordinate,GeometryError> where
+	I : Iterator<Item = P>,
+	P : Point2DLike{
+
+	try_signed_shoelace(points).map(|area| if area < P::Coordinate::zero() {-area} else {area})
+}
+
+///Like signed_shoelace, but returns an error for no points or a NaN or infinite coordinate instead of panicking.
+pub fn try_signed_shoelace<I,P>(points : I ) -> Result<P::Coordinate,GeometryError> where
+	I : Iterator<Item = P>,
+	P : Point2DLike{
+
+	try_signed_shoelace_doubled(points).map(|area| area / (P::Coordinate::one() + P::Coordinate::one()))
+}
+
+///Like signed_shoelace_doubled, but returns an error for no points or a NaN or infinite coordinate instead of panicking.
+pub fn try_signed_shoelace_doubled<I,P>(points : I ) -> Result<P::Coordinate,GeometryError> where
+	I : Iterator<Item = P>,
+	P : Point2DLike{
+
+	let mut points = points.enumerate().map(|(index,point)|
+		if is_finite(&point) {Ok(point.to_point())} else {Err(GeometryError::NonFiniteCoordinate(index))});
+
+	let first_point = points.next().ok_or(GeometryError::EmptyInput)??;
+	let mut last_point = first_point.clone();
 
-	area.abs() / T::from(2).unwrap()
+	let mut area = P::Coordinate::zero();
+	for point in points
+	{
+		let point = point?;
+		area = area + (point.x.clone() + last_point.x.clone())*(point.y.clone() - last_point.y.clone());
+		last_point = point;
+	}
+	area = area + (first_point.x + last_point.x)*(first_point.y - last_point.y);
 
+	Ok(area)
 }
 
 
@@ -30,8 +95,10 @@ pub fn shoelace<'a,T:Float+Zero+'a,I,P>(mut points : I ) -> T where
 
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod algorithms_test {
 	use super::*;
+	use crate::primatives2d::Point2D;
 
     #[test]
     fn shoelace_test() {
@@ -74,6 +141,68 @@ mod algorithms_test {
 			Point2D::new( -10.0,100.0),
 		); 
 		assert_eq!(shoelace(polygon.iter()),500.0 );
-	}    
+	}
+
+    #[test]
+    fn signed_shoelace_test() {
+		let clockwise = vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(1.0,0.0)
+		);
+		assert_eq!(signed_shoelace(clockwise.iter()),-1.0 );
+		assert_eq!(signed_shoelace(clockwise.iter().rev()),1.0 );
+	}
+
+    #[test]
+    fn integer_shoelace_test() {
+		let triangle = vec!(
+			Point2D::new(0i64,0),
+			Point2D::new(1,0),
+			Point2D::new(0,1)
+		);
+		assert_eq!(signed_shoelace_doubled(triangle.iter()),1 );
+		assert_eq!(signed_shoelace_doubled(triangle.iter().rev()),-1 );
+
+		let square = vec!(
+			Point2D::new(-2i32,-2),
+			Point2D::new(-2, 2),
+			Point2D::new( 2, 2),
+			Point2D::new( 2,-2)
+		);
+		assert_eq!(shoelace(square.iter()),16 );
+		assert_eq!(signed_shoelace(square.iter()),-16 );
+	}
+
+    #[test]
+    fn try_shoelace_test() {
+		let square = vec!((0.0,0.0),(0.0,2.0),(2.0,2.0),(2.0,0.0));
+		assert_eq!(try_shoelace(square.iter()),Ok(4.0));
+		assert_eq!(try_signed_shoelace(square.iter()),Ok(-4.0));
+		assert_eq!(try_signed_shoelace_doubled(square.iter().rev()),Ok(8.0));
+		assert_eq!(try_shoelace(vec!((1,1)).into_iter()),Ok(0));
+
+		let empty : Vec<Point2D<f64>> = Vec::new();
+		assert_eq!(try_shoelace(empty.iter()),Err(GeometryError::EmptyInput));
+		assert_eq!(try_signed_shoelace(vec!((0.0,0.0),(f64::NAN,1.0),(1.0,0.0)).into_iter()),Err(GeometryError::NonFiniteCoordinate(1)));
+		assert_eq!(try_shoelace(vec!((f64::INFINITY,0.0)).into_iter()),Err(GeometryError::NonFiniteCoordinate(0)));
+	}
+
+    #[test]
+    fn point2d_like_shoelace_test() {
+		let square = vec!((0.0,0.0),(0.0,2.0),(2.0,2.0),(2.0,0.0));
+		assert_eq!(shoelace(square.iter()),4.0 );
+		assert_eq!(signed_shoelace(square.into_iter().rev()),4.0 );
+
+		//smart pointers to points work as they did when shoelace took Deref<Target = Point2D>
+		let boxed : Vec<Box<Point2D<f64>>> = vec!(Box::new(Point2D::new(0.0,0.0)),Box::new(Point2D::new(0.0,3.0)),Box::new(Point2D::new(3.0,0.0)));
+		assert_eq!(shoelace(boxed.into_iter()),4.5 );
+		let shared : Vec<std::rc::Rc<Point2D<f64>>> = vec!(Point2D::new(0.0,0.0),Point2D::new(0.0,3.0),Point2D::new(3.0,0.0)).into_iter().map(std::rc::Rc::new).collect();
+		assert_eq!(signed_shoelace(shared.iter()),-4.5 );
+
+		let triangle = [[0i64,0],[4,0],[0,4]];
+		assert_eq!(signed_shoelace_doubled(triangle.iter()),16 );
+	}
 
 }
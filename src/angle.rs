@@ -0,0 +1,130 @@
+use crate::coordinate::FloatCoordinate;
+use std::ops::{Add,Sub,Mul,Neg};
+
+
+///Angle stored in radians, counter-clockwise positive.
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Angle<T: FloatCoordinate>{
+	///Size of the angle in radians
+	pub radians : T,
+}
+
+impl<T: FloatCoordinate> Angle<T >{
+	///Creates an angle from radians.
+	pub fn from_radians(radians: T) -> Self{
+		Angle{radians}
+	}
+
+	///Creates an angle from degrees.
+	pub fn from_degrees(degrees: T) -> Self{
+		Angle{radians: degrees.to_radians()}
+	}
+
+	///Returns the angle in radians.
+	pub fn radians(&self) -> T{
+		self.radians
+	}
+
+	///Returns the angle in degrees.
+	pub fn degrees(&self) -> T{
+		self.radians.to_degrees()
+	}
+
+	///Returns the equivalent angle in [0, 2π).
+	pub fn normalized(&self) -> Angle<T>{
+		let full_turn = Angle::full_turn().radians;
+		let radians = self.radians % full_turn;
+		let radians = if radians < T::zero() {radians + full_turn} else {radians};
+		//adding a full turn to a tiny negative remainder can round up to exactly 2π
+		Angle{radians: if radians >= full_turn {T::zero()} else {radians}}
+	}
+
+	///Returns the equivalent angle in (-π, π].
+	pub fn normalized_signed(&self) -> Angle<T>{
+		let normalized = self.normalized();
+		if normalized.radians > Angle::half_turn().radians{
+			Angle{radians: normalized.radians - Angle::full_turn().radians}
+		}
+		else{
+			normalized
+		}
+	}
+
+	///Returns the sine of the angle.
+	pub fn sin(&self) -> T{
+		self.radians.sin()
+	}
+
+	///Returns the cosine of the angle.
+	pub fn cos(&self) -> T{
+		self.radians.cos()
+	}
+
+	///Returns the angle of a half turn, π radians.
+	pub fn half_turn() -> Self{
+		Angle{radians: T::from(std::f64::consts::PI).unwrap()}
+	}
+
+	///Returns the angle of a full turn, 2π radians.
+	pub fn full_turn() -> Self{
+		Angle{radians: T::from(std::f64::consts::PI * 2.0).unwrap()}
+	}
+}
+
+impl<T: FloatCoordinate> Add for Angle<T> {
+	type Output = Angle<T>;
+	fn add(self, other: Angle<T>) -> Angle<T> {
+		Angle{radians: self.radians + other.radians}
+	}
+}
+
+impl<T: FloatCoordinate> Sub for Angle<T> {
+	type Output = Angle<T>;
+	fn sub(self, other: Angle<T>) -> Angle<T> {
+		Angle{radians: self.radians - other.radians}
+	}
+}
+
+impl<T: FloatCoordinate> Mul<T> for Angle<T> {
+	type Output = Angle<T>;
+	fn mul(self, scalar: T) -> Angle<T> {
+		Angle{radians: self.radians * scalar}
+	}
+}
+
+impl<T: FloatCoordinate> Neg for Angle<T> {
+	type Output = Angle<T>;
+	fn neg(self) -> Angle<T> {
+		Angle{radians: -self.radians}
+	}
+}
+
+
+#[cfg(test)]
+mod angle_test {
+	use super::*;
+	use std::f64::consts::PI;
+
+	#[test]
+	fn conversion_test() {
+		assert_eq!(Angle::from_degrees(180.0).radians(),PI);
+		assert_eq!(Angle::from_radians(PI / 2.0).degrees(),90.0);
+		assert_eq!((Angle::from_radians(1.0) + Angle::from_radians(0.5)).radians(),1.5);
+		assert_eq!((Angle::from_radians(1.0) - Angle::from_radians(0.5)).radians(),0.5);
+		assert_eq!((-Angle::from_radians(1.0) * 2.0).radians(),-2.0);
+		assert!(Angle::from_radians(1.0) < Angle::from_radians(2.0));
+	}
+
+	#[test]
+	fn normalize_test() {
+		assert_eq!(Angle::from_radians(3.0 * PI).normalized().radians(),PI);
+		assert_eq!(Angle::from_radians(-PI / 2.0).normalized().radians(),1.5 * PI);
+		assert_eq!(Angle::from_radians(2.0 * PI).normalized().radians(),0.0);
+		assert_eq!(Angle::from_radians(-1e-20).normalized().radians(),0.0);
+		assert_eq!(Angle::from_radians(1.5 * PI).normalized_signed().radians(),-PI / 2.0);
+		assert_eq!(Angle::from_radians(-PI).normalized_signed().radians(),PI);
+		assert_eq!(Angle::from_radians(PI).normalized_signed().radians(),PI);
+		assert_eq!(Angle::from_radians(0.5).normalized_signed().radians(),0.5);
+	}
+}
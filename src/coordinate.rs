@@ -0,0 +1,155 @@
+//!Number types usable as coordinates.
+//Point2D and the algorithms built only on orientation tests accept any Coordinate,
+//so they run exactly on integers and rationals. Operations that need square roots or
+//division, such as distances and intersection points, require a FloatCoordinate.
+
+use num_traits::{Float,Num};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::Neg;
+use crate::predicates;
+
+///Number type usable as a coordinate.
+pub trait Coordinate: Clone + PartialOrd + Num + Neg<Output = Self> + Debug {
+	///Returns Greater if a, b and c are in counter-clockwise order, Less if they are in clockwise order
+	///and Equal if they are collinear. Implementations must return the exact answer.
+	fn orient2d(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self) -> Ordering;
+
+	///Returns the sign of the cross product of b - a and d - c, Greater if d - c points counter-clockwise of b - a.
+	///Implementations must return the exact answer.
+	#[allow(clippy::too_many_arguments)]
+	fn cross_sign(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self, dx: &Self, dy: &Self) -> Ordering;
+}
+
+///Floating point coordinate, required by operations whose result is not exactly representable.
+pub trait FloatCoordinate: Coordinate + Float {}
+
+impl<T: Coordinate + Float> FloatCoordinate for T {}
+
+///Evaluates the orientation determinant directly, which is exact for types with exact arithmetic.
+fn exact_orient2d<T: Clone + PartialOrd + Num>(ax: &T, ay: &T, bx: &T, by: &T, cx: &T, cy: &T) -> Ordering {
+	let left  = (ax.clone() - cx.clone()) * (by.clone() - cy.clone());
+	let right = (ay.clone() - cy.clone()) * (bx.clone() - cx.clone());
+	left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+}
+
+///Evaluates the cross product directly, which is exact for types with exact arithmetic.
+#[allow(clippy::too_many_arguments)]
+fn exact_cross_sign<T: Clone + PartialOrd + Num>(ax: &T, ay: &T, bx: &T, by: &T, cx: &T, cy: &T, dx: &T, dy: &T) -> Ordering {
+	let left  = (bx.clone() - ax.clone()) * (dy.clone() - cy.clone());
+	let right = (by.clone() - ay.clone()) * (dx.clone() - cx.clone());
+	left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+}
+
+macro_rules! float_coordinate {
+	($($t:ty),*) => {$(
+		impl Coordinate for $t {
+			fn orient2d(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self) -> Ordering {
+				predicates::orient2d(*ax, *ay, *bx, *by, *cx, *cy).partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+			}
+
+			fn cross_sign(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self, dx: &Self, dy: &Self) -> Ordering {
+				predicates::cross(*ax, *ay, *bx, *by, *cx, *cy, *dx, *dy).partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+			}
+		}
+	)*};
+}
+
+//Differences of two values need one extra bit and their products twice as many,
+//so integers are widened to a type that can hold the determinant without overflow.
+macro_rules! widened_integer_coordinate {
+	($($t:ty => $wide:ty),*) => {$(
+		impl Coordinate for $t {
+			fn orient2d(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self) -> Ordering {
+				exact_orient2d(&(*ax as $wide), &(*ay as $wide), &(*bx as $wide), &(*by as $wide), &(*cx as $wide), &(*cy as $wide))
+			}
+
+			fn cross_sign(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self, dx: &Self, dy: &Self) -> Ordering {
+				exact_cross_sign(&(*ax as $wide), &(*ay as $wide), &(*bx as $wide), &(*by as $wide),
+					&(*cx as $wide), &(*cy as $wide), &(*dx as $wide), &(*dy as $wide))
+			}
+		}
+	)*};
+}
+
+float_coordinate!(f32, f64);
+widened_integer_coordinate!(i8 => i32, i16 => i64, i32 => i128);
+
+//The products of i64 differences need 129 bits, one more than i128 has.
+//Each difference fits in 64 bits of magnitude, so the products are compared as a sign and a u128 magnitude.
+impl Coordinate for i64 {
+	fn orient2d(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self) -> Ordering {
+		let difference = |a: &i64, b: &i64| *a as i128 - *b as i128;
+		compare_products(difference(ax,cx), difference(by,cy), difference(ay,cy), difference(bx,cx))
+	}
+
+	fn cross_sign(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self, dx: &Self, dy: &Self) -> Ordering {
+		let difference = |a: &i64, b: &i64| *a as i128 - *b as i128;
+		compare_products(difference(bx,ax), difference(dy,cy), difference(by,ay), difference(dx,cx))
+	}
+}
+
+///Compares a*b with c*d exactly, for factors whose magnitude fits in 64 bits.
+fn compare_products(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+	let left_sign  = a.signum() * b.signum();
+	let right_sign = c.signum() * d.signum();
+	if left_sign != right_sign {
+		return left_sign.cmp(&right_sign);
+	}
+	let left  = a.unsigned_abs() * b.unsigned_abs();
+	let right = c.unsigned_abs() * d.unsigned_abs();
+	if left_sign < 0 { right.cmp(&left) } else { left.cmp(&right) }
+}
+
+#[cfg(feature = "rational")]
+impl<T> Coordinate for num_rational::Ratio<T> where T: Clone + num_integer::Integer + Neg<Output = T> + Debug {
+	fn orient2d(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self) -> Ordering {
+		exact_orient2d(ax, ay, bx, by, cx, cy)
+	}
+
+	fn cross_sign(ax: &Self, ay: &Self, bx: &Self, by: &Self, cx: &Self, cy: &Self, dx: &Self, dy: &Self) -> Ordering {
+		exact_cross_sign(ax, ay, bx, by, cx, cy, dx, dy)
+	}
+}
+
+
+#[cfg(test)]
+mod coordinate_test {
+	use super::*;
+
+	#[test]
+	fn orient2d_test() {
+		assert_eq!(f64::orient2d(&0.0, &0.0, &1.0, &0.0, &0.0, &1.0), Ordering::Greater);
+		assert_eq!(i32::orient2d(&0, &0, &0, &1, &1, &0), Ordering::Less);
+		assert_eq!(i8::orient2d(&-128, &-128, &0, &0, &127, &127), Ordering::Equal);
+		assert_eq!(i8::orient2d(&-128, &-128, &127, &126, &127, &127), Ordering::Greater);
+		let big = 1i64 << 62;
+		assert_eq!(i64::orient2d(&-big, &-big, &big, &(big - 1), &big, &big), Ordering::Greater);
+
+		let (min, max) = (i64::MIN, i64::MAX);
+		assert_eq!(i64::orient2d(&min, &min, &max, &max, &1, &0), Ordering::Less);
+		assert_eq!(i64::orient2d(&min, &min, &max, &max, &0, &0), Ordering::Equal);
+		assert_eq!(i64::orient2d(&min, &min, &max, &(max - 1), &max, &max), Ordering::Greater);
+		assert_eq!(i64::orient2d(&min, &min, &(max - 1), &(max - 1), &max, &max), Ordering::Equal);
+		assert_eq!(i64::orient2d(&min, &max, &max, &min, &0, &0), Ordering::Greater);
+		assert_eq!(i64::orient2d(&min, &max, &max, &min, &-1, &0), Ordering::Equal);
+		assert_eq!(i64::orient2d(&min, &max, &max, &min, &-1, &-1), Ordering::Less);
+		assert_eq!(i64::orient2d(&max, &min, &min, &max, &max, &max), Ordering::Less);
+
+		assert_eq!(i64::cross_sign(&min, &min, &max, &max, &0, &0, &-1, &0), Ordering::Greater);
+		assert_eq!(i64::cross_sign(&min, &min, &max, &max, &max, &min, &min, &max), Ordering::Greater);
+		assert_eq!(i64::cross_sign(&0, &0, &max, &max, &min, &min, &max, &max), Ordering::Equal);
+		assert_eq!(i32::cross_sign(&0, &0, &1, &0, &5, &5, &5, &6), Ordering::Greater);
+		assert_eq!(f64::cross_sign(&0.0, &0.0, &1.0, &0.0, &5.0, &5.0, &5.0, &4.0), Ordering::Less);
+
+		//swapping two points flips the orientation and rotating them keeps it
+		use rand::RngExt;
+		for _ in 0..1000 {
+			let v: Vec<i64> = (0..6).map(|_| rand::rng().random()).collect();
+			let orientation = i64::orient2d(&v[0], &v[1], &v[2], &v[3], &v[4], &v[5]);
+			assert_eq!(i64::orient2d(&v[2], &v[3], &v[0], &v[1], &v[4], &v[5]), orientation.reverse());
+			assert_eq!(i64::orient2d(&v[2], &v[3], &v[4], &v[5], &v[0], &v[1]), orientation);
+			assert_eq!(i64::cross_sign(&v[4], &v[5], &v[0], &v[1], &v[4], &v[5], &v[2], &v[3]), orientation);
+		}
+	}
+}
@@ -1,7 +1,10 @@
 use crate::primatives2d::{Point2D};
+use crate::polygon2d::{Orientation,PolygonWithHoles,MultiPolygon};
 use crate::algorithms::polygon_area::shoelace;
-use num_traits::Float;
-use std::fmt;
+use crate::coordinate::FloatCoordinate;
+use crate::point2d_like::Point2DLike;
+use crate::error::{GeometryError,check_points};
+use crate::transform2d::{Transform,Transform2D};
 
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -11,7 +14,7 @@ type Ptr<T> = Rc<RefCell<T>>;
 
 
 ///Doublely Connected Edge List.
-pub struct DCEL<T:Float>{
+pub struct DCEL<T:FloatCoordinate>{
     vertices   : Vec<Ptr<Vertex<T>>>,
     faces      : Vec<Ptr<Face<T>>>,
     half_edges : Vec<Ptr<HalfEdge<T>>>,
@@ -20,7 +23,7 @@ pub struct DCEL<T:Float>{
 	half_edge_count :usize,
 }
 
-impl<T :Float> Drop for DCEL<T> {
+impl<T :FloatCoordinate> Drop for DCEL<T> {
     fn drop(&mut self) {
         for he in self.half_edges.iter(){
 			he.borrow_mut().clean();
@@ -34,41 +37,108 @@ impl<T :Float> Drop for DCEL<T> {
     }
 }
 
-impl<T :Float> DCEL<T>{
+impl<T :FloatCoordinate> DCEL<T>{
 
 
+	///Creates a DCEL with no vertices, faces or half edges.
 	pub fn create_empty() -> Self{
 		DCEL{vertices:Vec::new(),faces: Vec::new(),half_edges: Vec::new(),vertex_count:0,face_count:0,half_edge_count:0}
 	}
 
 
 
-	pub fn create_from_point_list(points : &[Point2D<T>]) -> Self{
+	///Creates a DCEL from a polygon given as a list of points, with an inner and an outer face.
+	///Vertex i of the DCEL is points[i]. Panics if points is empty, see try_create_from_point_list.
+	pub fn create_from_point_list<P>(points : &[P]) -> Self where P: Point2DLike<Coordinate = T>{
 		let mut dcel = DCEL::create_empty();
 
 		let outer_face = dcel.create_face();
 		let inner_face = dcel.create_face();
 
-		let start_vertex = dcel.create_vertex(points[0]);
-		let (out_edge,in_edge) = dcel.create_twin_edges();
-		out_edge.borrow_mut().incident_face = Some(outer_face.clone());
-		in_edge.borrow_mut().incident_face  = Some(inner_face.clone());
+		let points : Vec<Point2D<T>> = points.iter().map(|point| point.to_point()).collect();
+		let (out_edge,in_edge) = dcel.create_ring(&points,outer_face.clone(),inner_face.clone());
+
+		outer_face.borrow_mut().inner_component.push( out_edge);
+		inner_face.borrow_mut().outer_component = Some( in_edge);
+
+		dcel
+	}
+
+	///Creates a DCEL from a polygon given as a list of points, like create_from_point_list.
+	///Returns an error for no points or a NaN or infinite coordinate instead of panicking.
+	pub fn try_create_from_point_list<P>(points : &[P]) -> Result<Self,GeometryError> where P: Point2DLike<Coordinate = T>{
+		check_points(points)?;
+		Ok(DCEL::create_from_point_list(points))
+	}
+
+	///Creates a DCEL from a polygon with holes.
+	///Face 0 is the unbounded face, face 1 is the polygon and every hole gets its own face.
+	///The boundary of each hole is stored as an inner_component of the polygon's face.
+	pub fn create_from_polygon_with_holes(polygon : &PolygonWithHoles<T>) -> Self{
+		let mut dcel = DCEL::create_empty();
+
+		let outer_face = dcel.create_face();
+		dcel.add_polygon_with_holes(polygon,outer_face);
+
+		dcel
+	}
+
+	///Creates a DCEL from a multi polygon.
+	///Face 0 is the unbounded face, every part and every hole gets its own face.
+	pub fn create_from_multi_polygon(multi_polygon : &MultiPolygon<T>) -> Self{
+		let mut dcel = DCEL::create_empty();
+
+		let outer_face = dcel.create_face();
+		for polygon in &multi_polygon.polygons{
+			dcel.add_polygon_with_holes(polygon,outer_face.clone());
+		}
+
+		dcel
+	}
+
+	///Adds a polygon with holes inside outer_face.
+	fn add_polygon_with_holes(&mut self, polygon : &PolygonWithHoles<T>, outer_face: Ptr<Face<T>>){
+		let polygon_face = self.create_face();
+
+		let mut outer_points = polygon.outer.clone();
+		outer_points.normalize_orientation(Orientation::Clockwise);
+		let (out_edge,in_edge) = self.create_ring(outer_points.points(),outer_face.clone(),polygon_face.clone());
+		outer_face.borrow_mut().inner_component.push(out_edge);
+		polygon_face.borrow_mut().outer_component = Some(in_edge);
+
+		for hole in &polygon.holes{
+			let hole_face = self.create_face();
+
+			let mut hole_points = hole.clone();
+			hole_points.normalize_orientation(Orientation::Clockwise);
+			let (out_edge,in_edge) = self.create_ring(hole_points.points(),polygon_face.clone(),hole_face.clone());
+			polygon_face.borrow_mut().inner_component.push(out_edge);
+			hole_face.borrow_mut().outer_component = Some(in_edge);
+		}
+	}
+
+	///Creates a closed ring of twin edges through the points.
+	///The edges following the points belong to left_face, their twins to right_face.
+	///For clockwise points left_face is outside the ring and right_face inside.
+	///The first edge of each side is returned.
+	fn create_ring(&mut self, points : &[Point2D<T>], left_face: Ptr<Face<T>>, right_face: Ptr<Face<T>>) -> (Ptr<HalfEdge<T>>,Ptr<HalfEdge<T>>){
+		let start_vertex = self.create_vertex(points[0]);
+		let (out_edge,in_edge) = self.create_twin_edges();
+		out_edge.borrow_mut().incident_face = Some(left_face.clone());
+		in_edge.borrow_mut().incident_face  = Some(right_face.clone());
 
 		out_edge.borrow_mut().origin = Some(start_vertex.clone());
 		start_vertex.borrow_mut().incident_edge = Some(out_edge.clone());
 
-		outer_face.borrow_mut().inner_component.push( out_edge.clone());
-		inner_face.borrow_mut().outer_component = Some( in_edge.clone());
-
 		let ( mut last_outer_edge, mut last_inner_edge) =(out_edge.clone(),in_edge.clone());
 
 		for &point in  points.iter().skip(1){
-			let (out_edge,in_edge) = dcel.create_twin_edges();
+			let (out_edge,in_edge) = self.create_twin_edges();
 
-			out_edge.borrow_mut().incident_face = Some(outer_face.clone());
-			in_edge.borrow_mut().incident_face  = Some(inner_face.clone());
+			out_edge.borrow_mut().incident_face = Some(left_face.clone());
+			in_edge.borrow_mut().incident_face  = Some(right_face.clone());
 
-			let vertex = dcel.create_vertex(point);
+			let vertex = self.create_vertex(point);
 			out_edge.borrow_mut().origin = Some(vertex.clone());
 			vertex.borrow_mut().incident_edge = Some(out_edge.clone());
 
@@ -91,8 +161,7 @@ impl<T :Float> DCEL<T>{
 		out_edge.borrow_mut().prev  = Some(last_outer_edge.clone());
 		in_edge.borrow_mut().next = Some(last_inner_edge.clone());
 
-
-		dcel
+		(out_edge,in_edge)
 	}
 
 	fn verify(&self) -> Result<bool,String>{
@@ -186,6 +255,7 @@ impl<T :Float> DCEL<T>{
 	///inserts a edge from the origin of half_edge1 to the orgin of half_edge2. half_edge2 get a new face.
 	///Does not check if there is any intersections. All inner_component are keep with original face.
 	///The twin pair of new half edges and the new face are returned.
+	#[allow(clippy::type_complexity)]
 	fn unchecked_divide_face(&mut self, half_edge1: Ptr<HalfEdge<T>>, half_edge2: Ptr<HalfEdge<T>>) -> (Ptr<HalfEdge<T>>,Ptr<HalfEdge<T>>,Ptr<Face<T>>){
 		let half_edge1_prev = half_edge1.borrow().prev.as_ref().unwrap().clone();
 		let half_edge2_prev = half_edge2.borrow().prev.as_ref().unwrap().clone();
@@ -294,7 +364,7 @@ impl<T :Float> DCEL<T>{
 	fn get_polygon_area_inclusive(&self, face:  Ptr<Face<T >>) -> T{
 
 		shoelace(PolygonIterator::new(face.borrow().outer_component.as_ref().unwrap().clone())
-			.map(|edge| Box::new(edge.borrow().origin.as_ref().unwrap().borrow().coordinate))
+			.map(|edge| edge.borrow().origin.as_ref().unwrap().borrow().coordinate)
 			)
 
 	}
@@ -314,7 +384,7 @@ impl<T :Float> DCEL<T>{
 
 
 
-	///
+	///Returns the points of every face with an outer component.
 	fn get_polygons(&self) -> Vec<Vec<Point2D<T>>>{
 		let mut polygon_list = Vec::new();
 
@@ -329,42 +399,210 @@ impl<T :Float> DCEL<T>{
 }
 
 
-struct Vertex<T:Float>{
+impl<T:FloatCoordinate> DCEL<T>{
+	///Turns every half edge around, each starts where its twin started and next and prev swap.
+	fn reverse_half_edges(&mut self){
+		let destinations : Vec<Option<Ptr<Vertex<T>>>> = self.half_edges.iter()
+			.map(|edge| edge.borrow().twin.as_ref().and_then(|twin| twin.borrow().origin.clone()))
+			.collect();
+		for (edge,destination) in self.half_edges.iter().zip(destinations){
+			let mut edge = edge.borrow_mut();
+			edge.origin = destination;
+			let next = edge.next.take();
+			edge.next = edge.prev.take();
+			edge.prev = next;
+		}
+		//the twin of the old incident edge now starts at the vertex
+		for vertex in self.vertices.iter(){
+			let twin = vertex.borrow().incident_edge.as_ref().and_then(|edge| edge.borrow().twin.clone());
+			vertex.borrow_mut().incident_edge = twin;
+		}
+	}
+}
+
+impl<T:FloatCoordinate> Transform<T> for DCEL<T> {
+	///Moves every vertex. Mirroring transformations also turn every half edge around,
+	///so each face stays on the same side of its boundary and keeps its orientation.
+	fn transform(&mut self, transform: &Transform2D<T>){
+		for vertex in self.vertices.iter(){
+			let mut vertex = vertex.borrow_mut();
+			vertex.coordinate = transform.apply_to_point(&vertex.coordinate);
+		}
+		if transform.determinant() < T::zero(){
+			self.reverse_half_edges();
+		}
+	}
+}
+
+///Index based form of a DCEL used for serialization.
+///Every link refers to a position in the vertex, face or half edge list.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedDCEL<T:FloatCoordinate>{
+	vertices   : Vec<SerializedVertex<T>>,
+	faces      : Vec<SerializedFace>,
+	half_edges : Vec<SerializedHalfEdge>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedVertex<T:FloatCoordinate>{
+	coordinate    : Point2D<T>,
+	incident_edge : Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedFace{
+	outer_component : Option<usize>,
+	inner_component : Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedHalfEdge{
+	origin        : Option<usize>,
+	twin          : Option<usize>,
+	next          : Option<usize>,
+	prev          : Option<usize>,
+	incident_face : Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl<T:FloatCoordinate> From<&DCEL<T>> for SerializedDCEL<T> {
+	fn from(dcel: &DCEL<T>) -> Self {
+		fn index<N>(ptr: &Option<Ptr<N>>, get: fn(&N) -> usize) -> Option<usize>{
+			ptr.as_ref().map(|ptr| get(&ptr.borrow()))
+		}
+
+		SerializedDCEL{
+			vertices: dcel.vertices.iter().map(|vertex| {
+				let vertex = vertex.borrow();
+				SerializedVertex{coordinate: vertex.coordinate,incident_edge: index(&vertex.incident_edge,|e| e.index)}
+			}).collect(),
+			faces: dcel.faces.iter().map(|face| {
+				let face = face.borrow();
+				SerializedFace{
+					outer_component: index(&face.outer_component,|e| e.index),
+					inner_component: face.inner_component.iter().map(|edge| edge.borrow().index).collect(),
+				}
+			}).collect(),
+			half_edges: dcel.half_edges.iter().map(|edge| {
+				let edge = edge.borrow();
+				SerializedHalfEdge{
+					origin: index(&edge.origin,|v| v.index),
+					twin: index(&edge.twin,|e| e.index),
+					next: index(&edge.next,|e| e.index),
+					prev: index(&edge.prev,|e| e.index),
+					incident_face: index(&edge.incident_face,|f| f.index),
+				}
+			}).collect(),
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T:FloatCoordinate> DCEL<T> {
+	///Rebuilds the linked DCEL from its index based form.
+	///Fails if an index is out of range or the links are inconsistent, as checked by verify.
+	fn from_serialized(serialized: SerializedDCEL<T>) -> Result<Self,String>{
+		fn link<N>(list: &[Ptr<N>], index: Option<usize>, name: &str) -> Result<Option<Ptr<N>>,String>{
+			match index{
+				Some(index) => list.get(index).cloned().map(Some).ok_or(format!("{} index {} is out of range",name,index)),
+				None => Ok(None),
+			}
+		}
+
+		let mut dcel = DCEL::create_empty();
+		for vertex in &serialized.vertices{
+			dcel.create_vertex(vertex.coordinate);
+		}
+		for _ in &serialized.faces{
+			dcel.create_face();
+		}
+		for _ in &serialized.half_edges{
+			let index = dcel.half_edge_count;
+			dcel.half_edge_count += 1;
+			let edge = HalfEdge{index,origin: None,twin: None,next: None,prev: None,incident_face: None};
+			dcel.half_edges.push(Rc::new(RefCell::new(edge)));
+		}
+
+		for (vertex,serialized) in dcel.vertices.iter().zip(&serialized.vertices){
+			vertex.borrow_mut().incident_edge = link(&dcel.half_edges,serialized.incident_edge,"Half edge")?;
+		}
+		for (face,serialized) in dcel.faces.iter().zip(&serialized.faces){
+			let mut face = face.borrow_mut();
+			face.outer_component = link(&dcel.half_edges,serialized.outer_component,"Half edge")?;
+			for &inner in &serialized.inner_component{
+				face.inner_component.push(link(&dcel.half_edges,Some(inner),"Half edge")?.unwrap());
+			}
+		}
+		for (edge,serialized) in dcel.half_edges.iter().zip(&serialized.half_edges){
+			let mut edge = edge.borrow_mut();
+			edge.origin = link(&dcel.vertices,serialized.origin,"Vertex")?;
+			edge.twin = link(&dcel.half_edges,serialized.twin,"Half edge")?;
+			edge.next = link(&dcel.half_edges,serialized.next,"Half edge")?;
+			edge.prev = link(&dcel.half_edges,serialized.prev,"Half edge")?;
+			edge.incident_face = link(&dcel.faces,serialized.incident_face,"Face")?;
+		}
+
+		dcel.verify()?;
+		Ok(dcel)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T:FloatCoordinate + serde::Serialize> serde::Serialize for DCEL<T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok,S::Error> {
+		SerializedDCEL::from(self).serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de,T:FloatCoordinate + serde::Deserialize<'de>> serde::Deserialize<'de> for DCEL<T> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self,D::Error> {
+		let serialized = SerializedDCEL::deserialize(deserializer)?;
+		DCEL::from_serialized(serialized).map_err(serde::de::Error::custom)
+	}
+}
+
+
+struct Vertex<T:FloatCoordinate>{
 	index		  : usize,
     coordinate    : Point2D<T>,
     incident_edge : Option<Ptr<HalfEdge<T>>>,
 }
-impl<T:Float> Vertex<T>{
+impl<T:FloatCoordinate> Vertex<T>{
 	fn clean(&mut self) {
 		self.incident_edge = None;
 	}
 }
-impl<T:Float> PartialEq for Vertex<T> {
+impl<T:FloatCoordinate> PartialEq for Vertex<T> {
     fn eq(&self, other: &Vertex<T>) -> bool {
 		self.index == other.index
 	}
 }
 
-struct Face<T:Float>{
+struct Face<T:FloatCoordinate>{
 	index		   : usize,
     outer_component: Option<Ptr<HalfEdge<T>>>,
     inner_component: Vec<Ptr<HalfEdge<T>>>,
 
 }
 
-impl<T:Float> Face<T>{
+impl<T:FloatCoordinate> Face<T>{
 	fn clean(&mut self) {
 		self.outer_component = None;
 		self.inner_component.clear();
 	}
 }
-impl<T:Float> PartialEq for Face<T> {
+impl<T:FloatCoordinate> PartialEq for Face<T> {
     fn eq(&self, other: &Face<T>) -> bool {
 		self.index == other.index
 	}
 }
 
-struct HalfEdge<T:Float>{
+struct HalfEdge<T:FloatCoordinate>{
 	index			: usize,
     origin          : Option<Ptr<Vertex<T>>>,
     twin            : Option<Ptr<HalfEdge<T>>>,
@@ -374,7 +612,7 @@ struct HalfEdge<T:Float>{
 
 }
 
-impl<T:Float> HalfEdge<T>{
+impl<T:FloatCoordinate> HalfEdge<T>{
 	fn clean(&mut self){
 		self.origin =None;
 		self.twin =None;
@@ -384,26 +622,26 @@ impl<T:Float> HalfEdge<T>{
 	}
 }
 
-impl<T:Float> PartialEq for HalfEdge<T> {
+impl<T:FloatCoordinate> PartialEq for HalfEdge<T> {
     fn eq(&self, other: &HalfEdge<T>) -> bool {
 		self.index == other.index
 	}
 }
 
-struct PolygonIterator<T: Float>{
+struct PolygonIterator<T: FloatCoordinate>{
 	starting_edge : Ptr<HalfEdge<T>>,
 	current_edge  : Ptr<HalfEdge<T>>,
 	finished : bool
 }
 
-impl<T: Float> PolygonIterator<T>{
+impl<T: FloatCoordinate> PolygonIterator<T>{
 	fn new(starting_edge: Ptr<HalfEdge<T>>) -> Self{
 		PolygonIterator{current_edge : starting_edge.clone(),starting_edge,finished: false}
 	}
 
 }
 
-impl<T:Float> Iterator for PolygonIterator<T> {
+impl<T:FloatCoordinate> Iterator for PolygonIterator<T> {
     type Item = Ptr<HalfEdge<T>>;
     fn next(&mut self) -> Option<Self::Item> {
 
@@ -433,6 +671,8 @@ impl<T:Float> Iterator for PolygonIterator<T> {
 #[cfg(test)]
 mod algorithms_test {
 	use super::*;
+	use crate::algorithms::polygon_area::signed_shoelace;
+	use crate::test_fixtures::rect;
 
     #[test]
     fn create_from_point_list_test() {
@@ -446,6 +686,17 @@ mod algorithms_test {
 		assert!(dcel.verify().unwrap());
 	}
 
+	#[test]
+    fn try_create_from_point_list_test() {
+		let dcel = DCEL::try_create_from_point_list(&[(0.0,0.0),(0.0,1.0),(1.0,0.0)]).unwrap();
+		assert!(dcel.verify().unwrap());
+		assert_eq!(dcel.vertices.len(),3);
+
+		let empty : [Point2D<f64>;0] = [];
+		assert_eq!(DCEL::try_create_from_point_list(&empty).err(),Some(GeometryError::EmptyInput));
+		assert_eq!(DCEL::try_create_from_point_list(&[(0.0,0.0),(0.0,1.0),(1.0,f64::NAN)]).err(),Some(GeometryError::NonFiniteCoordinate(2)));
+	}
+
 
 	#[test]
     fn unchecked_divide_face_test() {
@@ -462,6 +713,41 @@ mod algorithms_test {
 		assert_eq!(dcel.faces.len(),3);
 	}
 
+	#[test]
+    fn create_from_polygon_with_holes_test() {
+		let polygon = PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0),rect(5.0,5.0,6.0,8.0)));
+		let dcel = DCEL::create_from_polygon_with_holes(&polygon);
+		assert!(dcel.verify().unwrap());
+		assert_eq!(dcel.faces.len(),4);
+		assert_eq!(dcel.vertices.len(),12);
+		assert_eq!(dcel.half_edges.len(),24);
+
+		assert_eq!(dcel.faces[0].borrow().inner_component.len(),1);
+		assert!(dcel.faces[0].borrow().outer_component.is_none());
+		assert_eq!(dcel.faces[1].borrow().inner_component.len(),2);
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[1].clone()), 100.0 );
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[2].clone()), 4.0 );
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[3].clone()), 3.0 );
+
+		for face in dcel.faces.iter().skip(1){
+			let points = dcel.get_polygon_points_from_face(face.clone());
+			assert!(signed_shoelace(points.iter()) > 0.0);
+		}
+	}
+
+	#[test]
+    fn create_from_multi_polygon_test() {
+		let multi = MultiPolygon::new(vec!(
+			PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0))),
+			PolygonWithHoles::from(rect(20.0,0.0,22.0,1.0)),
+		));
+		let dcel = DCEL::create_from_multi_polygon(&multi);
+		assert!(dcel.verify().unwrap());
+		assert_eq!(dcel.faces.len(),4);
+		assert_eq!(dcel.faces[0].borrow().inner_component.len(),2);
+		assert_eq!(dcel.get_polygons().len(),3);
+	}
+
 	#[test]
     fn get_polygon_area_inclusive_test() {
 		let points = vec!(
@@ -470,10 +756,68 @@ mod algorithms_test {
 			Point2D::new(1.0,1.0),
 			Point2D::new(1.0,0.0)
 		);
-		let mut dcel = DCEL::create_from_point_list(&points);
+		let dcel = DCEL::create_from_point_list(&points);
 		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[1].clone()), 1.0 );
+
+		let dcel = DCEL::create_from_point_list(&[(0.0,0.0),(0.0,2.0),(2.0,2.0),(2.0,0.0)]);
+		assert!(dcel.verify().unwrap());
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[1].clone()), 4.0 );
+		assert_eq!(dcel.vertices[1].borrow().coordinate,Point2D::new(0.0,2.0));
 	}
 
+	#[test]
+    fn transform_test() {
+		use crate::primatives2d::Vector2D;
+
+		let polygon = PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0)));
+		let mut dcel = DCEL::create_from_polygon_with_holes(&polygon);
+		dcel.transform(&Transform2D::translation(Vector2D::new(5.0,-2.0)).then(&Transform2D::scale(2.0,3.0)));
 
+		assert!(dcel.verify().unwrap());
+		assert_eq!(dcel.faces.len(),3);
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[1].clone()), 600.0 );
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[2].clone()), 24.0 );
+		assert!(dcel.get_polygon_points_from_face(dcel.faces[2].clone()).contains(&Point2D::new(12.0,-3.0)));
+
+		//mirroring keeps every face boundary going the same way around its face
+		let mut dcel = DCEL::create_from_polygon_with_holes(&polygon);
+		let orientations = |dcel: &DCEL<f64>| dcel.faces.iter().skip(1)
+			.map(|face| signed_shoelace(dcel.get_polygon_points_from_face(face.clone()).iter()) > 0.0)
+			.collect::<Vec<bool>>();
+		let before = orientations(&dcel);
+		dcel.transform(&Transform2D::scale(-1.0,2.0));
+		assert!(dcel.verify().unwrap());
+		assert_eq!(orientations(&dcel),before);
+		assert_eq!(dcel.get_polygon_area_inclusive(dcel.faces[1].clone()), 200.0 );
+		assert!(dcel.get_polygon_points_from_face(dcel.faces[2].clone()).contains(&Point2D::new(-3.0,6.0)));
+		let outer = dcel.faces[0].borrow().inner_component[0].clone();
+		assert!(signed_shoelace(PolygonIterator::new(outer).map(|edge| edge.borrow().origin.as_ref().unwrap().borrow().coordinate)) < 0.0);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+    fn serde_test() {
+		let polygon = PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0)));
+		let dcel = DCEL::create_from_polygon_with_holes(&polygon);
+
+		let json = serde_json::to_string(&dcel).unwrap();
+		let copy : DCEL<f64> = serde_json::from_str(&json).unwrap();
+		assert!(copy.verify().unwrap());
+		assert_eq!(copy.faces.len(),3);
+		assert_eq!(copy.half_edges.len(),16);
+		assert_eq!(copy.faces[1].borrow().inner_component.len(),1);
+		assert_eq!(copy.get_polygon_area_inclusive(copy.faces[1].clone()), 100.0 );
+		assert_eq!(copy.get_polygon_points_from_face(copy.faces[2].clone()),dcel.get_polygon_points_from_face(dcel.faces[2].clone()));
+		assert_eq!(serde_json::to_string(&copy).unwrap(),json);
+
+		//break the twin link of the first half edge
+		let mut value : serde_json::Value = serde_json::from_str(&json).unwrap();
+		value["half_edges"][0]["twin"] = serde_json::json!(2);
+		assert!(serde_json::from_value::<DCEL<f64>>(value).is_err());
+
+		let mut value : serde_json::Value = serde_json::from_str(&json).unwrap();
+		value["half_edges"][0]["origin"] = serde_json::json!(100);
+		assert!(serde_json::from_value::<DCEL<f64>>(value).is_err());
+	}
 
 }
@@ -0,0 +1,60 @@
+use crate::point2d_like::Point2DLike;
+use num_traits::Zero;
+use std::fmt;
+
+
+///Reasons a set of points can not be used by an algorithm.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum GeometryError{
+	///No points were given.
+	EmptyInput,
+	///The point at this index has a NaN or infinite coordinate.
+	NonFiniteCoordinate(usize),
+}
+
+impl fmt::Display for GeometryError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self{
+			GeometryError::EmptyInput => write!(f,"No points were given"),
+			GeometryError::NonFiniteCoordinate(index) => write!(f,"Point {} has a non finite coordinate",index),
+		}
+	}
+}
+
+impl std::error::Error for GeometryError {}
+
+//Returns true if both coordinates are finite, integer and rational coordinates always are.
+//x - x is zero for every finite value and NaN for NaN and the infinities.
+pub(crate) fn is_finite<P: Point2DLike>(point: &P) -> bool{
+	let (x,y) = (point.x(),point.y());
+	(x.clone() - x).is_zero() && (y.clone() - y).is_zero()
+}
+
+//Checks the points are not empty and all finite.
+pub(crate) fn check_points<P: Point2DLike>(points: &[P]) -> Result<(),GeometryError>{
+	if points.is_empty(){
+		return Err(GeometryError::EmptyInput);
+	}
+	match points.iter().position(|point| !is_finite(point)){
+		Some(index) => Err(GeometryError::NonFiniteCoordinate(index)),
+		None => Ok(()),
+	}
+}
+
+
+#[cfg(test)]
+mod error_test {
+	use super::*;
+	use crate::primatives2d::Point2D;
+
+	#[test]
+	fn check_points_test() {
+		let empty : [Point2D<f64>;0] = [];
+		assert_eq!(check_points(&empty),Err(GeometryError::EmptyInput));
+		assert_eq!(check_points(&[(0.0,0.0),(1.0,f64::INFINITY)]),Err(GeometryError::NonFiniteCoordinate(1)));
+		assert_eq!(check_points(&[(f64::NAN,0.0)]),Err(GeometryError::NonFiniteCoordinate(0)));
+		assert_eq!(check_points(&[(0.0,0.0),(1.0,2.0)]),Ok(()));
+		assert_eq!(check_points(&[(i64::MAX,i64::MIN)]),Ok(()));
+		assert_eq!(GeometryError::NonFiniteCoordinate(3).to_string(),"Point 3 has a non finite coordinate");
+	}
+}
@@ -4,12 +4,33 @@
 #![warn(missing_docs)]
 
 pub use crate::{
-    primatives2d::{Point2D,Line2D},
+    primatives2d::{Point2D,TotalPoint2D,Vector2D,Line2D,InfiniteLine2D,Ray2D,Rect2D,Circle2D,CirclePosition,Triangle2D,SegmentIntersection,LinearIntersection,TurnDirection},
+    primatives3d::{Point3D,Vector3D,Line3D,Plane3D,PlaneSide},
+    polygon2d::{Polygon2D,PolygonWithHoles,MultiPolygon,PolygonEdges,Orientation,PolygonError},
+    polyline2d::{Polyline2D,PolylineSegments,PolylineError},
     dcel::DCEL,
+    coordinate::{Coordinate,FloatCoordinate},
+    point2d_like::{Point2DLike,Segment2DLike},
+    error::GeometryError,
+    tolerance::{Tolerance,ApproxEq},
+    angle::Angle,
+    transform2d::{Transform2D,Transform},
 };
 
 mod primatives2d;
+mod primatives3d;
+mod polygon2d;
+mod polyline2d;
 mod dcel;
+mod coordinate;
+mod point2d_like;
+mod error;
+mod tolerance;
+mod angle;
+mod transform2d;
 pub mod algorithms;
+pub mod predicates;
+#[cfg(test)]
+mod test_fixtures;
 
 
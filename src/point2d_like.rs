@@ -0,0 +1,222 @@
+use crate::primatives2d::{Point2D,Line2D,TurnDirection};
+use crate::coordinate::Coordinate;
+use std::cmp::Ordering;
+use std::rc::Rc;
+use std::sync::Arc;
+
+
+///Point type that algorithms can read x and y coordinates from.
+///Implement it for your own point types to pass them to the algorithms without copying them into Point2D.
+pub trait Point2DLike{
+	///Number type of the coordinates.
+	type Coordinate: Coordinate;
+
+	///Returns the x coordinate.
+	fn x(&self) -> Self::Coordinate;
+
+	///Returns the y coordinate.
+	fn y(&self) -> Self::Coordinate;
+
+	///Returns the point as a Point2D.
+	fn to_point(&self) -> Point2D<Self::Coordinate>{
+		Point2D::new(self.x(),self.y())
+	}
+
+	///Returns the rotational direction of the points (self,p1,p2), see Point2D::turn_direction.
+	fn turn_direction(&self, p1: &Self, p2: &Self) -> TurnDirection{
+		match Self::Coordinate::orient2d(&self.x(), &self.y(), &p1.x(), &p1.y(), &p2.x(), &p2.y()){
+			Ordering::Less    => TurnDirection::RightTurn,
+			Ordering::Greater => TurnDirection::LeftTurn,
+			Ordering::Equal   => TurnDirection::NoTurn,
+		}
+	}
+
+	///Partial comparision function ordered by x value then by y in case of ties.
+	fn x_then_y_partial_cmp(&self, other: &Self) -> Option<Ordering>{
+		match self.x().partial_cmp(&other.x()){
+			Some(Ordering::Equal) => self.y().partial_cmp(&other.y()),
+			ordering => ordering,
+		}
+	}
+}
+
+impl<T: Coordinate> Point2DLike for Point2D<T> {
+	type Coordinate = T;
+
+	fn x(&self) -> T{
+		self.x.clone()
+	}
+
+	fn y(&self) -> T{
+		self.y.clone()
+	}
+
+	fn to_point(&self) -> Point2D<T>{
+		self.clone()
+	}
+}
+
+impl<P: Point2DLike> Point2DLike for &P {
+	type Coordinate = P::Coordinate;
+
+	fn x(&self) -> P::Coordinate{
+		(*self).x()
+	}
+
+	fn y(&self) -> P::Coordinate{
+		(*self).y()
+	}
+}
+
+impl<T: Coordinate> Point2DLike for (T,T) {
+	type Coordinate = T;
+
+	fn x(&self) -> T{
+		self.0.clone()
+	}
+
+	fn y(&self) -> T{
+		self.1.clone()
+	}
+}
+
+impl<T: Coordinate> Point2DLike for [T;2] {
+	type Coordinate = T;
+
+	fn x(&self) -> T{
+		self[0].clone()
+	}
+
+	fn y(&self) -> T{
+		self[1].clone()
+	}
+}
+
+impl<P: Point2DLike> Point2DLike for Box<P> {
+	type Coordinate = P::Coordinate;
+
+	fn x(&self) -> P::Coordinate{
+		(**self).x()
+	}
+
+	fn y(&self) -> P::Coordinate{
+		(**self).y()
+	}
+}
+
+impl<P: Point2DLike> Point2DLike for Rc<P> {
+	type Coordinate = P::Coordinate;
+
+	fn x(&self) -> P::Coordinate{
+		(**self).x()
+	}
+
+	fn y(&self) -> P::Coordinate{
+		(**self).y()
+	}
+}
+
+impl<P: Point2DLike> Point2DLike for Arc<P> {
+	type Coordinate = P::Coordinate;
+
+	fn x(&self) -> P::Coordinate{
+		(**self).x()
+	}
+
+	fn y(&self) -> P::Coordinate{
+		(**self).y()
+	}
+}
+
+///Line segment type that algorithms can read the end points of.
+///Implement it for your own segment types to pass them to the algorithms without copying them into Line2D.
+pub trait Segment2DLike{
+	///Point type of the end points.
+	type Point: Point2DLike;
+
+	///Returns the first end point.
+	fn start(&self) -> &Self::Point;
+
+	///Returns the second end point.
+	fn end(&self) -> &Self::Point;
+}
+
+impl<T: Coordinate> Segment2DLike for Line2D<T> {
+	type Point = Point2D<T>;
+
+	fn start(&self) -> &Point2D<T>{
+		&self.p1
+	}
+
+	fn end(&self) -> &Point2D<T>{
+		&self.p2
+	}
+}
+
+impl<S: Segment2DLike> Segment2DLike for &S {
+	type Point = S::Point;
+
+	fn start(&self) -> &S::Point{
+		(*self).start()
+	}
+
+	fn end(&self) -> &S::Point{
+		(*self).end()
+	}
+}
+
+impl<P: Point2DLike> Segment2DLike for (P,P) {
+	type Point = P;
+
+	fn start(&self) -> &P{
+		&self.0
+	}
+
+	fn end(&self) -> &P{
+		&self.1
+	}
+}
+
+impl<P: Point2DLike> Segment2DLike for [P;2] {
+	type Point = P;
+
+	fn start(&self) -> &P{
+		&self[0]
+	}
+
+	fn end(&self) -> &P{
+		&self[1]
+	}
+}
+
+
+#[cfg(test)]
+mod point2d_like_test {
+	use super::*;
+
+	#[test]
+	fn point2d_like_test() {
+		let point = Point2D::new(1.0,2.0);
+		assert_eq!(Point2DLike::x(&point),1.0);
+		assert_eq!((3,4).to_point(),Point2D::new(3,4));
+		assert_eq!([3,4].y(),4);
+
+		assert_eq!((0,0).turn_direction(&(1,0),&(1,1)),TurnDirection::LeftTurn);
+		assert_eq!([0.0,0.0].turn_direction(&[1.0,0.0],&[2.0,0.0]),TurnDirection::NoTurn);
+		assert_eq!((1,5).x_then_y_partial_cmp(&(1,3)),Some(Ordering::Greater));
+		assert_eq!((1.0,f64::NAN).x_then_y_partial_cmp(&(1.0,3.0)),None);
+
+		assert_eq!(Box::new((1,2)).to_point(),Point2D::new(1,2));
+		assert_eq!(Rc::new(point).y(),2.0);
+		assert_eq!(Arc::new([5,6]).x(),5);
+	}
+
+	#[test]
+	fn segment2d_like_test() {
+		let line = Line2D::new(Point2D::new(1.0,2.0),Point2D::new(3.0,4.0));
+		assert_eq!(line.start(),&Point2D::new(1.0,2.0));
+		assert_eq!(line.end(),&Point2D::new(3.0,4.0));
+		assert_eq!(((0,1),(2,3)).end().x(),2);
+		assert_eq!([[0,1],[2,3]].start().y(),1);
+	}
+}
@@ -0,0 +1,521 @@
+use crate::primatives2d::{Point2D,TotalPoint2D,Line2D,Rect2D,SegmentIntersection,TurnDirection};
+use crate::algorithms::polygon_area::{shoelace,signed_shoelace};
+use crate::coordinate::FloatCoordinate;
+use crate::transform2d::{Transform,Transform2D};
+use crate::tolerance::{Tolerance,ApproxEq};
+use std::fmt;
+use std::convert::TryFrom;
+use std::collections::HashSet;
+
+
+///2D Polygon stored as a ring of points, the last point connects back to the first.
+#[derive(Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<Point2D<T>>", into = "Vec<Point2D<T>>"))]
+pub struct Polygon2D<T: FloatCoordinate>{
+	points : Vec<Point2D<T>>,
+}
+
+impl<T: FloatCoordinate> Polygon2D<T >{
+
+	///Creates a polygon from a ring of points.
+	///Fails if there are fewer than 3 points, a coordinate is not finite or all points are collinear.
+	pub fn new(points: Vec<Point2D<T>>) -> Result<Self,PolygonError>{
+		if points.len() < 3{
+			return Err(PolygonError::TooFewVertices(points.len()));
+		}
+		if let Some(index) = points.iter().position(|p| !p.x.is_finite() || !p.y.is_finite()){
+			return Err(PolygonError::NonFiniteCoordinate(index));
+		}
+		let first = points[0];
+		let second = match points.iter().find(|&&p| p != first){
+			Some(&p) => p,
+			None => return Err(PolygonError::ZeroArea),
+		};
+		if points.iter().all(|p| first.turn_direction(&second,p) == TurnDirection::NoTurn){
+			return Err(PolygonError::ZeroArea);
+		}
+
+		Ok(Polygon2D{points})
+	}
+
+	///Creates a polygon from a ring of points without validating it.
+	///The area and orientation of a polygon without vertices panic, its bounding box is None.
+	pub fn new_unchecked(points: Vec<Point2D<T>>) -> Self{
+		Polygon2D{points}
+	}
+
+	///Returns the points of the polygon.
+	pub fn points(&self) -> &[Point2D<T>]{
+		&self.points
+	}
+
+	///Returns the number of vertices.
+	pub fn len(&self) -> usize{
+		self.points.len()
+	}
+
+	///Returns true if the polygon has no vertices.
+	pub fn is_empty(&self) -> bool{
+		self.points.is_empty()
+	}
+
+	///Returns an iterator over the edges of the polygon, including the closing edge.
+	pub fn edges(&self) -> PolygonEdges<'_,T>{
+		PolygonEdges{points: &self.points,index: 0}
+	}
+
+	///Returns the area of the polygon.
+	///Panics if the polygon has no vertices, which only new_unchecked can make.
+	pub fn area(&self) -> T{
+		shoelace(self.points.iter())
+	}
+
+	///Returns the area, positive for counter-clockwise polygons and negative for clockwise ones.
+	///Panics if the polygon has no vertices, which only new_unchecked can make.
+	pub fn signed_area(&self) -> T{
+		signed_shoelace(self.points.iter())
+	}
+
+	///Returns the smallest axis aligned rectangle containing the polygon,
+	///or None for a polygon without vertices made with new_unchecked.
+	pub fn bounding_box(&self) -> Option<Rect2D<T>>{
+		Rect2D::from_points(&self.points)
+	}
+
+	///Returns the orientation of the ring, based on the sign of its area.
+	///Panics if the polygon has no vertices, which only new_unchecked can make.
+	pub fn orientation(&self) -> Orientation{
+		if self.signed_area() < T::zero(){
+			Orientation::Clockwise
+		}
+		else{
+			Orientation::CounterClockwise
+		}
+	}
+
+	///Reverses the order of the points, flipping the orientation.
+	pub fn reverse(&mut self){
+		self.points.reverse();
+	}
+
+	///Reverses the points if needed so the polygon has the given orientation.
+	///Panics if the polygon has no vertices, which only new_unchecked can make.
+	pub fn normalize_orientation(&mut self, orientation: Orientation){
+		if self.orientation() != orientation{
+			self.reverse();
+		}
+	}
+
+	///Returns the indices of vertices that are equal to an earlier vertex.
+	pub fn duplicate_vertices(&self) -> Vec<usize>{
+		let mut seen = HashSet::new();
+		(0..self.points.len())
+			.filter(|&i| !seen.insert(TotalPoint2D::new(self.points[i])))
+			.collect()
+	}
+
+	///Returns the indices of vertices that are collinear with both of their neighbours.
+	pub fn collinear_vertices(&self) -> Vec<usize>{
+		let n = self.points.len();
+		(0..n)
+			.filter(|&i|{
+				let prev = self.points[(i + n - 1) % n];
+				let next = self.points[(i + 1) % n];
+				prev.turn_direction(&self.points[i],&next) == TurnDirection::NoTurn
+			})
+			.collect()
+	}
+
+	///Returns true if no two edges intersect, other than neighbouring edges at their shared vertex.
+	pub fn is_simple(&self) -> bool{
+		let edges: Vec<Line2D<T>> = self.edges().collect();
+		let n = edges.len();
+
+		for i in 0..n{
+			for j in (i + 1)..n{
+				let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+				match edges[i].intersection(&edges[j]){
+					SegmentIntersection::None => {},
+					SegmentIntersection::Point(p) if adjacent => {
+						let shared = if j == i + 1 {edges[i].p2} else {edges[i].p1};
+						if p != shared{
+							return false;
+						}
+					},
+					_ => return false,
+				}
+			}
+		}
+		true
+	}
+}
+
+///2D Polygon with an outer ring and any number of holes.
+///The holes are expected to lie inside the outer ring and not to overlap each other.
+#[derive(Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolygonWithHoles<T: FloatCoordinate>{
+	///Outer boundary
+	pub outer : Polygon2D<T>,
+	///Inner boundaries
+	pub holes : Vec<Polygon2D<T>>,
+}
+
+impl<T: FloatCoordinate> PolygonWithHoles<T >{
+
+	///Creates a polygon from an outer ring and its holes.
+	pub fn new(outer: Polygon2D<T>, holes: Vec<Polygon2D<T>>) -> Self{
+		PolygonWithHoles{outer,holes}
+	}
+
+	///Returns the area of the outer ring minus the area of the holes.
+	///Panics if a ring has no vertices, like Polygon2D::area.
+	pub fn area(&self) -> T{
+		self.holes.iter().fold(self.outer.area(),|area,hole| area - hole.area())
+	}
+
+	///Orients the outer ring counter-clockwise and the holes clockwise.
+	///Panics if a ring has no vertices, like Polygon2D::orientation.
+	pub fn normalize_orientation(&mut self){
+		self.outer.normalize_orientation(Orientation::CounterClockwise);
+		for hole in self.holes.iter_mut(){
+			hole.normalize_orientation(Orientation::Clockwise);
+		}
+	}
+
+	///Returns the smallest axis aligned rectangle containing the polygon, or None if the outer ring has no vertices.
+	pub fn bounding_box(&self) -> Option<Rect2D<T>>{
+		self.outer.bounding_box()
+	}
+}
+
+impl<T: FloatCoordinate> From<Polygon2D<T>> for PolygonWithHoles<T> {
+	fn from(outer: Polygon2D<T>) -> Self {
+		PolygonWithHoles::new(outer,Vec::new())
+	}
+}
+
+///Collection of polygons that do not overlap.
+#[derive(Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiPolygon<T: FloatCoordinate>{
+	///Parts of the multi polygon
+	pub polygons : Vec<PolygonWithHoles<T>>,
+}
+
+impl<T: FloatCoordinate> MultiPolygon<T >{
+
+	///Creates a multi polygon from its parts.
+	pub fn new(polygons: Vec<PolygonWithHoles<T>>) -> Self{
+		MultiPolygon{polygons}
+	}
+
+	///Returns the total area of all parts.
+	///Panics if a ring has no vertices, like Polygon2D::area.
+	pub fn area(&self) -> T{
+		self.polygons.iter().fold(T::zero(),|area,polygon| area + polygon.area())
+	}
+
+	///Orients every outer ring counter-clockwise and every hole clockwise.
+	///Panics if a ring has no vertices, like Polygon2D::orientation.
+	pub fn normalize_orientation(&mut self){
+		for polygon in self.polygons.iter_mut(){
+			polygon.normalize_orientation();
+		}
+	}
+
+	///Returns the smallest axis aligned rectangle containing every part, or None if there are no vertices.
+	pub fn bounding_box(&self) -> Option<Rect2D<T>>{
+		self.polygons.iter()
+			.filter_map(|polygon| polygon.bounding_box())
+			.fold(None,|total: Option<Rect2D<T>>,rect| Some(total.map_or(rect,|total| total.union(&rect))))
+	}
+}
+
+impl<T: FloatCoordinate> TryFrom<Vec<Point2D<T>>> for Polygon2D<T> {
+	type Error = PolygonError;
+	fn try_from(points: Vec<Point2D<T>>) -> Result<Self,PolygonError> {
+		Polygon2D::new(points)
+	}
+}
+
+impl<T: FloatCoordinate> From<Polygon2D<T>> for Vec<Point2D<T>> {
+	fn from(polygon: Polygon2D<T>) -> Self {
+		polygon.points
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for Polygon2D<T> {
+	///Mirroring transformations reverse the orientation of the polygon.
+	fn transform(&mut self, transform: &Transform2D<T>){
+		self.points.transform(transform);
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for PolygonWithHoles<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		self.outer.transform(transform);
+		for hole in self.holes.iter_mut(){
+			hole.transform(transform);
+		}
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for MultiPolygon<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		for polygon in self.polygons.iter_mut(){
+			polygon.transform(transform);
+		}
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Polygon2D<T> {
+	///Polygons are equal if they have the same number of vertices and each vertex is equal,
+	///starting from the same vertex and going in the same direction.
+	fn approx_eq(&self, other: &Polygon2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.points[..].approx_eq(&other.points[..],tolerance)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for PolygonWithHoles<T> {
+	fn approx_eq(&self, other: &PolygonWithHoles<T>, tolerance: &Tolerance<T>) -> bool {
+		self.outer.approx_eq(&other.outer,tolerance) && self.holes[..].approx_eq(&other.holes[..],tolerance)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for MultiPolygon<T> {
+	fn approx_eq(&self, other: &MultiPolygon<T>, tolerance: &Tolerance<T>) -> bool {
+		self.polygons[..].approx_eq(&other.polygons[..],tolerance)
+	}
+}
+
+///Iterator over the edges of a Polygon2D.
+pub struct PolygonEdges<'a,T: FloatCoordinate>{
+	points : &'a [Point2D<T>],
+	index  : usize,
+}
+
+impl<'a,T: FloatCoordinate> Iterator for PolygonEdges<'a,T> {
+	type Item = Line2D<T>;
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index < self.points.len(){
+			let p1 = self.points[self.index];
+			let p2 = self.points[(self.index + 1) % self.points.len()];
+			self.index += 1;
+			Some(Line2D::new(p1,p2))
+		}
+		else{
+			None
+		}
+	}
+}
+
+///Enum representing the winding order of a ring of points.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation{
+	///Points turn right.
+	Clockwise,
+	///Points turn left.
+	CounterClockwise,
+}
+
+///Reasons a ring of points can not form a polygon.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum PolygonError{
+	///A polygon needs at least 3 vertices, holds the number given.
+	TooFewVertices(usize),
+	///The vertex at this index has a NaN or infinite coordinate.
+	NonFiniteCoordinate(usize),
+	///All vertices are collinear.
+	ZeroArea,
+}
+
+impl fmt::Display for PolygonError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self{
+			PolygonError::TooFewVertices(count) => write!(f,"A polygon needs at least 3 vertices, but {} were given",count),
+			PolygonError::NonFiniteCoordinate(index) => write!(f,"Vertex {} has a non finite coordinate",index),
+			PolygonError::ZeroArea => write!(f,"All vertices are collinear, the polygon has no area"),
+		}
+	}
+}
+
+impl std::error::Error for PolygonError {}
+
+
+#[cfg(test)]
+mod polygon_test {
+	use super::*;
+	use crate::test_fixtures::rect;
+
+	fn square() -> Vec<Point2D<f64>>{
+		vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(1.0,0.0)
+		)
+	}
+
+	#[test]
+	fn new_test() {
+		assert!(Polygon2D::new(square()).is_ok());
+		assert_eq!(Polygon2D::new(vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0))), Err(PolygonError::TooFewVertices(2)));
+		assert_eq!(Polygon2D::new(vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0),Point2D::new(f64::NAN,0.0))), Err(PolygonError::NonFiniteCoordinate(2)));
+		assert_eq!(Polygon2D::new(vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,1.0),Point2D::new(2.0,2.0))), Err(PolygonError::ZeroArea));
+		assert_eq!(Polygon2D::new(vec!(Point2D::new(1.0,1.0),Point2D::new(1.0,1.0),Point2D::new(1.0,1.0))), Err(PolygonError::ZeroArea));
+		assert_eq!(PolygonError::TooFewVertices(2).to_string(), "A polygon needs at least 3 vertices, but 2 were given");
+	}
+
+	#[test]
+	fn edges_test() {
+		let polygon = Polygon2D::new(square()).unwrap();
+		let edges: Vec<Line2D<f64>> = polygon.edges().collect();
+		assert_eq!(edges.len(), 4);
+		assert_eq!(edges[0], Line2D::new(Point2D::new(0.0,0.0),Point2D::new(0.0,1.0)));
+		assert_eq!(edges[3], Line2D::new(Point2D::new(1.0,0.0),Point2D::new(0.0,0.0)));
+	}
+
+	#[test]
+	fn orientation_test() {
+		let mut polygon = Polygon2D::new(square()).unwrap();
+		assert_eq!(polygon.orientation(), Orientation::Clockwise);
+		assert_eq!(polygon.signed_area(), -1.0);
+		assert_eq!(polygon.area(), 1.0);
+		polygon.normalize_orientation(Orientation::CounterClockwise);
+		assert_eq!(polygon.orientation(), Orientation::CounterClockwise);
+		assert_eq!(polygon.points()[0], Point2D::new(1.0,0.0));
+		polygon.normalize_orientation(Orientation::CounterClockwise);
+		assert_eq!(polygon.points()[0], Point2D::new(1.0,0.0));
+	}
+
+	#[test]
+	fn bounding_box_test() {
+		assert_eq!(rect(1.0,2.0,3.0,-4.0).bounding_box(), Some(Rect2D::new(Point2D::new(1.0,-4.0),Point2D::new(3.0,2.0))));
+		let empty = Polygon2D::<f64>::new_unchecked(Vec::new());
+		assert_eq!(empty.bounding_box(), None);
+		assert_eq!(PolygonWithHoles::from(empty.clone()).bounding_box(), None);
+		assert_eq!(MultiPolygon::new(vec!(PolygonWithHoles::from(empty),PolygonWithHoles::from(rect(0.0,0.0,1.0,1.0)))).bounding_box(),
+			Some(Rect2D::new(Point2D::new(0.0,0.0),Point2D::new(1.0,1.0))));
+	}
+
+	#[test]
+	fn degenerate_vertices_test() {
+		let polygon = Polygon2D::new(vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(0.0,2.0),
+			Point2D::new(2.0,2.0),
+			Point2D::new(2.0,2.0),
+			Point2D::new(2.0,0.0)
+		)).unwrap();
+		assert_eq!(polygon.duplicate_vertices(), vec!(4));
+		assert_eq!(polygon.collinear_vertices(), vec!(1,3,4));
+		assert!(Polygon2D::new(square()).unwrap().duplicate_vertices().is_empty());
+		assert!(Polygon2D::new(square()).unwrap().collinear_vertices().is_empty());
+	}
+
+	#[test]
+	fn polygon_with_holes_test() {
+		let mut polygon = PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0),rect(5.0,5.0,6.0,8.0)));
+		assert_eq!(polygon.area(), 93.0);
+
+		let mut reversed = rect(1.0,1.0,3.0,3.0);
+		reversed.reverse();
+		polygon.holes.push(reversed);
+		assert_eq!(polygon.area(), 89.0);
+
+		polygon.normalize_orientation();
+		assert_eq!(polygon.outer.orientation(), Orientation::CounterClockwise);
+		assert!(polygon.holes.iter().all(|hole| hole.orientation() == Orientation::Clockwise));
+		assert_eq!(polygon.area(), 89.0);
+	}
+
+	#[test]
+	fn multi_polygon_test() {
+		let mut multi = MultiPolygon::new(vec!(
+			PolygonWithHoles::new(rect(0.0,0.0,10.0,10.0),vec!(rect(1.0,1.0,3.0,3.0))),
+			PolygonWithHoles::from(rect(20.0,0.0,22.0,-1.0)),
+		));
+		assert_eq!(multi.area(), 98.0);
+		assert_eq!(multi.bounding_box(), Some(Rect2D::new(Point2D::new(0.0,-1.0),Point2D::new(22.0,10.0))));
+		assert_eq!(MultiPolygon::<f64>::new(Vec::new()).bounding_box(), None);
+
+		multi.normalize_orientation();
+		assert!(multi.polygons.iter().all(|p| p.outer.orientation() == Orientation::CounterClockwise));
+		assert_eq!(multi.area(), 98.0);
+	}
+
+	#[test]
+	fn approx_eq_test() {
+		let tolerance = Tolerance::default();
+		let polygon = rect(0.0,0.0,0.3,1.0);
+		let close = rect(0.0,0.0,0.1 + 0.2,1.0);
+		assert!(polygon.approx_eq(&close,&tolerance));
+		assert!(!polygon.approx_eq(&rect(0.0,0.0,0.31,1.0),&tolerance));
+		let mut reversed = close.clone();
+		reversed.reverse();
+		assert!(!polygon.approx_eq(&reversed,&tolerance));
+
+		let with_hole = PolygonWithHoles::new(rect(0.0,0.0,3.0,3.0),vec!(polygon.clone()));
+		assert!(with_hole.approx_eq(&PolygonWithHoles::new(rect(0.0,0.0,3.0,3.0),vec!(close.clone())),&tolerance));
+		assert!(!with_hole.approx_eq(&PolygonWithHoles::from(rect(0.0,0.0,3.0,3.0)),&tolerance));
+
+		let multi = MultiPolygon::new(vec!(with_hole.clone(),PolygonWithHoles::from(close)));
+		assert!(multi.approx_eq(&multi.clone(),&tolerance));
+		assert!(!multi.approx_eq(&MultiPolygon::new(vec!(with_hole)),&tolerance));
+	}
+
+	#[test]
+	fn is_simple_test() {
+		assert!(Polygon2D::new(square()).unwrap().is_simple());
+
+		let bowtie = Polygon2D::new(vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(1.0,0.0),
+			Point2D::new(0.0,1.0)
+		)).unwrap();
+		assert!(!bowtie.is_simple());
+
+		let spike = Polygon2D::new(vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(0.0,1.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(0.5,1.0),
+			Point2D::new(1.0,0.0)
+		)).unwrap();
+		assert!(!spike.is_simple());
+
+		let touching = Polygon2D::new(vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(2.0,0.0),
+			Point2D::new(2.0,2.0),
+			Point2D::new(1.0,0.0),
+			Point2D::new(0.0,2.0)
+		)).unwrap();
+		assert!(!touching.is_simple());
+
+		let concave = Polygon2D::new(vec!(
+			Point2D::new(0.0,0.0),
+			Point2D::new(2.0,0.0),
+			Point2D::new(2.0,2.0),
+			Point2D::new(1.0,1.0),
+			Point2D::new(0.0,2.0)
+		)).unwrap();
+		assert!(concave.is_simple());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+    fn serde_test() {
+		let polygon = Polygon2D::new(vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0),Point2D::new(0.0,1.0))).unwrap();
+		let json = serde_json::to_string(&polygon).unwrap();
+		assert_eq!(json,r#"[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0},{"x":0.0,"y":1.0}]"#);
+		assert_eq!(serde_json::from_str::<Polygon2D<f64>>(&json).unwrap(),polygon);
+
+		assert!(serde_json::from_str::<Polygon2D<f64>>(r#"[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0}]"#).is_err());
+	}
+}
@@ -0,0 +1,305 @@
+use crate::primatives2d::{Point2D,Line2D,Rect2D};
+use crate::coordinate::FloatCoordinate;
+use crate::transform2d::{Transform,Transform2D};
+use crate::tolerance::{Tolerance,ApproxEq};
+use std::fmt;
+use std::convert::TryFrom;
+
+
+///2D Polyline, an open chain of points joined by line segments.
+///Distances along the polyline are measured from the first point.
+#[derive(Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<Point2D<T>>", into = "Vec<Point2D<T>>"))]
+pub struct Polyline2D<T: FloatCoordinate>{
+	points : Vec<Point2D<T>>,
+}
+
+impl<T: FloatCoordinate> Polyline2D<T >{
+
+	///Creates a polyline through the points in order.
+	///Fails if there are fewer than 2 points or a coordinate is not finite.
+	pub fn new(points: Vec<Point2D<T>>) -> Result<Self,PolylineError>{
+		if points.len() < 2{
+			return Err(PolylineError::TooFewVertices(points.len()));
+		}
+		if let Some(index) = points.iter().position(|p| !p.x.is_finite() || !p.y.is_finite()){
+			return Err(PolylineError::NonFiniteCoordinate(index));
+		}
+
+		Ok(Polyline2D{points})
+	}
+
+	///Returns the points of the polyline.
+	pub fn points(&self) -> &[Point2D<T>]{
+		&self.points
+	}
+
+	///Returns the number of vertices.
+	pub fn len(&self) -> usize{
+		self.points.len()
+	}
+
+	///Returns true if the polyline has no vertices.
+	pub fn is_empty(&self) -> bool{
+		self.points.is_empty()
+	}
+
+	///Returns an iterator over the segments of the polyline.
+	pub fn segments(&self) -> PolylineSegments<'_,T>{
+		PolylineSegments{points: &self.points,index: 0}
+	}
+
+	///Returns the total length of the segments.
+	pub fn length(&self) -> T{
+		self.segments().fold(T::zero(),|length,segment| length + segment.length())
+	}
+
+	///Returns the smallest axis aligned rectangle containing the polyline.
+	pub fn bounding_box(&self) -> Rect2D<T>{
+		Rect2D::from_points(&self.points).expect("a polyline has at least 2 points")
+	}
+
+	///Reverses the direction of the polyline.
+	pub fn reverse(&mut self){
+		self.points.reverse();
+	}
+
+	///Returns the point at 'distance' along the polyline.
+	///Distances are clamped to the polyline, so negative distances give the first point
+	///and distances past the end give the last point.
+	pub fn point_at_distance(&self, distance: T) -> Point2D<T>{
+		let mut travelled = T::zero();
+		for segment in self.segments(){
+			let length = segment.length();
+			if distance < travelled + length{
+				let t = ((distance - travelled) / length).max(T::zero());
+				return segment.p1 + segment.direction() * t;
+			}
+			travelled = travelled + length;
+		}
+		self.points[self.points.len() - 1]
+	}
+
+	///Returns the distance along the polyline of the point closest to 'point'.
+	///If several points are equally close the one nearest the start is used.
+	pub fn locate_point(&self, point: &Point2D<T>) -> T{
+		let mut travelled = T::zero();
+		let mut best_distance = T::infinity();
+		let mut best_location = T::zero();
+
+		for segment in self.segments(){
+			let length = segment.length();
+			let t = segment.projection_parameter(point).max(T::zero()).min(T::one());
+			let distance = point.distance(&(segment.p1 + segment.direction() * t));
+			if distance < best_distance{
+				best_distance = distance;
+				best_location = travelled + length * t;
+			}
+			travelled = travelled + length;
+		}
+		best_location
+	}
+
+	///Returns the part of the polyline between the distances 'start' and 'end', both clamped to the polyline.
+	///If 'start' is after 'end' the result runs backwards.
+	pub fn sub_line(&self, start: T, end: T) -> Polyline2D<T>{
+		if start > end{
+			let mut sub_line = self.sub_line(end,start);
+			sub_line.reverse();
+			return sub_line;
+		}
+
+		let length = self.length();
+		let start = start.max(T::zero()).min(length);
+		let end = end.max(T::zero()).min(length);
+
+		let mut points = vec!(self.point_at_distance(start));
+		let mut travelled = T::zero();
+		for segment in self.segments(){
+			travelled = travelled + segment.length();
+			if travelled >= end{
+				break;
+			}
+			if travelled > start{
+				points.push(segment.p2);
+			}
+		}
+		points.push(self.point_at_distance(end));
+
+		Polyline2D{points}
+	}
+
+	///Returns a polyline with points every 'spacing' along this one, ending with the last point.
+	///The last segment is shorter when the length is not a multiple of 'spacing'.
+	///Returns None if 'spacing' is not a positive finite number.
+	pub fn resample(&self, spacing: T) -> Option<Polyline2D<T>>{
+		if spacing <= T::zero() || !spacing.is_finite(){
+			return None;
+		}
+
+		let mut points = vec!(self.points[0]);
+		let mut count = T::one();
+		let mut travelled = T::zero();
+		for segment in self.segments(){
+			let length = segment.length();
+			while spacing * count < travelled + length{
+				let t = (spacing * count - travelled) / length;
+				points.push(segment.p1 + segment.direction() * t);
+				count = count + T::one();
+			}
+			travelled = travelled + length;
+		}
+		points.push(self.points[self.points.len() - 1]);
+
+		Some(Polyline2D{points})
+	}
+}
+
+impl<T: FloatCoordinate> TryFrom<Vec<Point2D<T>>> for Polyline2D<T> {
+	type Error = PolylineError;
+	fn try_from(points: Vec<Point2D<T>>) -> Result<Self,PolylineError> {
+		Polyline2D::new(points)
+	}
+}
+
+impl<T: FloatCoordinate> From<Polyline2D<T>> for Vec<Point2D<T>> {
+	fn from(polyline: Polyline2D<T>) -> Self {
+		polyline.points
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for Polyline2D<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		self.points.transform(transform);
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Polyline2D<T> {
+	///Polylines are equal if they have the same number of vertices and each vertex is equal.
+	fn approx_eq(&self, other: &Polyline2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.points[..].approx_eq(&other.points[..],tolerance)
+	}
+}
+
+///Iterator over the segments of a Polyline2D.
+pub struct PolylineSegments<'a,T: FloatCoordinate>{
+	points : &'a [Point2D<T>],
+	index  : usize,
+}
+
+impl<'a,T: FloatCoordinate> Iterator for PolylineSegments<'a,T> {
+	type Item = Line2D<T>;
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index + 1 < self.points.len(){
+			let segment = Line2D::new(self.points[self.index],self.points[self.index + 1]);
+			self.index += 1;
+			Some(segment)
+		}
+		else{
+			None
+		}
+	}
+}
+
+///Reasons a list of points can not form a polyline.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum PolylineError{
+	///A polyline needs at least 2 vertices, holds the number given.
+	TooFewVertices(usize),
+	///The vertex at this index has a NaN or infinite coordinate.
+	NonFiniteCoordinate(usize),
+}
+
+impl fmt::Display for PolylineError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self{
+			PolylineError::TooFewVertices(count) => write!(f,"A polyline needs at least 2 vertices, but {} were given",count),
+			PolylineError::NonFiniteCoordinate(index) => write!(f,"Vertex {} has a non finite coordinate",index),
+		}
+	}
+}
+
+impl std::error::Error for PolylineError {}
+
+
+#[cfg(test)]
+mod polyline_test {
+	use super::*;
+
+	fn p(x: f64,y: f64) -> Point2D<f64>{
+		Point2D::new(x,y)
+	}
+
+	//L shaped line of length 7, 3 along x then 4 along y
+	fn l_shape() -> Polyline2D<f64>{
+		Polyline2D::new(vec!(p(0.0,0.0),p(3.0,0.0),p(3.0,4.0))).unwrap()
+	}
+
+	#[test]
+	fn new_test() {
+		assert_eq!(Polyline2D::new(vec!(p(0.0,0.0))), Err(PolylineError::TooFewVertices(1)));
+		assert_eq!(Polyline2D::new(vec!(p(0.0,0.0),p(f64::NAN,1.0))), Err(PolylineError::NonFiniteCoordinate(1)));
+
+		let line = l_shape();
+		assert_eq!(line.len(),3);
+		assert_eq!(line.segments().collect::<Vec<_>>(),vec!(Line2D::new(p(0.0,0.0),p(3.0,0.0)),Line2D::new(p(3.0,0.0),p(3.0,4.0))));
+		assert_eq!(line.length(),7.0);
+		assert_eq!(line.bounding_box(),Rect2D::new(p(0.0,0.0),p(3.0,4.0)));
+	}
+
+	#[test]
+	fn approx_eq_test() {
+		let tolerance = Tolerance::default();
+		let close = Polyline2D::new(vec!(p(0.0,0.0),p(0.1 + 0.2,0.0),p(3.0,4.0))).unwrap();
+		assert!(Polyline2D::new(vec!(p(0.0,0.0),p(0.3,0.0),p(3.0,4.0))).unwrap().approx_eq(&close,&tolerance));
+		assert!(!l_shape().approx_eq(&close,&tolerance));
+		assert!(!l_shape().approx_eq(&Polyline2D::new(vec!(p(0.0,0.0),p(3.0,0.0))).unwrap(),&tolerance));
+	}
+
+	#[test]
+	fn point_at_distance_test() {
+		let line = l_shape();
+		assert_eq!(line.point_at_distance(-1.0),p(0.0,0.0));
+		assert_eq!(line.point_at_distance(0.0),p(0.0,0.0));
+		assert_eq!(line.point_at_distance(1.5),p(1.5,0.0));
+		assert_eq!(line.point_at_distance(3.0),p(3.0,0.0));
+		assert_eq!(line.point_at_distance(5.0),p(3.0,2.0));
+		assert_eq!(line.point_at_distance(7.0),p(3.0,4.0));
+		assert_eq!(line.point_at_distance(10.0),p(3.0,4.0));
+
+		let repeated = Polyline2D::new(vec!(p(0.0,0.0),p(0.0,0.0),p(2.0,0.0))).unwrap();
+		assert_eq!(repeated.point_at_distance(1.0),p(1.0,0.0));
+	}
+
+	#[test]
+	fn locate_point_test() {
+		let line = l_shape();
+		assert_eq!(line.locate_point(&p(1.0,-2.0)),1.0);
+		assert_eq!(line.locate_point(&p(5.0,3.0)),6.0);
+		assert_eq!(line.locate_point(&p(-1.0,-1.0)),0.0);
+		assert_eq!(line.locate_point(&p(3.0,10.0)),7.0);
+		assert_eq!(line.locate_point(&line.point_at_distance(4.5)),4.5);
+	}
+
+	#[test]
+	fn sub_line_test() {
+		let line = l_shape();
+		assert_eq!(line.sub_line(1.0,5.0).points(),&[p(1.0,0.0),p(3.0,0.0),p(3.0,2.0)]);
+		assert_eq!(line.sub_line(1.0,2.0).points(),&[p(1.0,0.0),p(2.0,0.0)]);
+		assert_eq!(line.sub_line(3.0,7.0).points(),&[p(3.0,0.0),p(3.0,4.0)]);
+		assert_eq!(line.sub_line(-5.0,50.0),line);
+		assert_eq!(line.sub_line(5.0,1.0).points(),&[p(3.0,2.0),p(3.0,0.0),p(1.0,0.0)]);
+		assert_eq!(line.sub_line(2.0,2.0).points(),&[p(2.0,0.0),p(2.0,0.0)]);
+	}
+
+	#[test]
+	fn resample_test() {
+		let line = l_shape();
+		assert_eq!(line.resample(2.0).unwrap().points(),&[p(0.0,0.0),p(2.0,0.0),p(3.0,1.0),p(3.0,3.0),p(3.0,4.0)]);
+		assert_eq!(line.resample(7.0).unwrap().points(),&[p(0.0,0.0),p(3.0,4.0)]);
+		assert_eq!(line.resample(100.0).unwrap().points(),&[p(0.0,0.0),p(3.0,4.0)]);
+		assert_eq!(line.resample(0.0),None);
+		assert_eq!(line.resample(f64::NAN),None);
+	}
+}
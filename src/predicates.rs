@@ -0,0 +1,601 @@
+//!Robust geometric predicates.
+//Adaptive precision predicates based on Jonathan Shewchuk's
+//"Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+//A fast floating-point filter is tried first, exact expansion arithmetic is only used when it is inconclusive.
+
+use num_traits::Float;
+
+///Returns a positive value if a, b and c are in counter-clockwise order, a negative value if they are in clockwise order
+///and zero if they are collinear. Only the sign of the result is exact.
+///Coordinates so large that the determinant would overflow are first scaled down by a power of two,
+///which keeps the sign unless it makes coordinates many orders of magnitude smaller underflow.
+///Coordinates so small that the products would become subnormal are scaled up the same way.
+pub fn orient2d<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T) -> T {
+	let detleft = (ax - cx) * (by - cy);
+	let detright = (ay - cy) * (bx - cx);
+	let det = detleft - detright;
+
+	let rescaled = || exact_scale(&[ax, ay, bx, by, cx, cy], 2)
+		.map(|s| orient2d(ax * s, ay * s, bx * s, by * s, cx * s, cy * s));
+	if !det.is_finite() || detleft.abs().max(detright.abs()) < underflow_bound() {
+		if let Some(det) = rescaled() {
+			return det;
+		}
+	}
+
+	let detsum = if detleft > T::zero() {
+		if detright <= T::zero() {
+			return det;
+		}
+		detleft + detright
+	}
+	else if detleft < T::zero() {
+		if detright >= T::zero() {
+			return det;
+		}
+		-detleft - detright
+	}
+	else {
+		return det;
+	};
+
+	let errbound = ccw_error_bound::<T>() * detsum;
+	if det >= errbound || -det >= errbound {
+		return det;
+	}
+
+	rescaled().unwrap_or_else(|| orient2d_exact(ax, ay, bx, by, cx, cy))
+}
+
+///Returns a value with the sign of the cross product of b - a and d - c, positive if d - c points
+///counter-clockwise of b - a. With c equal to a this is orient2d(a, b, d). Only the sign of the result is exact.
+///Very large and very small coordinates are rescaled like in orient2d.
+#[allow(clippy::too_many_arguments)]
+pub fn cross<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T, dx: T, dy: T) -> T {
+	//the same four differences, two products and one subtraction as orient2d, so the same error bound holds
+	let detleft = (bx - ax) * (dy - cy);
+	let detright = (by - ay) * (dx - cx);
+	let det = detleft - detright;
+
+	let detsum = detleft.abs() + detright.abs();
+	let errbound = ccw_error_bound::<T>() * detsum;
+	if (det > errbound || -det > errbound) && detsum >= underflow_bound() {
+		return det;
+	}
+
+	match exact_scale(&[ax, ay, bx, by, cx, cy, dx, dy], 2){
+		Some(s) => cross(ax * s, ay * s, bx * s, by * s, cx * s, cy * s, dx * s, dy * s),
+		None => cross_exact(ax, ay, bx, by, cx, cy, dx, dy),
+	}
+}
+
+///Returns a positive value if d lies inside the circle through a, b and c, a negative value if it lies outside
+///and zero if the four points are cocircular. a, b and c must be in counter-clockwise order, otherwise the sign is reversed.
+///Only the sign of the result is exact. Very large and very small coordinates are rescaled like in orient2d.
+#[allow(clippy::too_many_arguments)]
+pub fn in_circle<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T, dx: T, dy: T) -> T {
+	let adx = ax - dx;
+	let bdx = bx - dx;
+	let cdx = cx - dx;
+	let ady = ay - dy;
+	let bdy = by - dy;
+	let cdy = cy - dy;
+
+	let bdxcdy = bdx * cdy;
+	let cdxbdy = cdx * bdy;
+	let alift = adx * adx + ady * ady;
+
+	let cdxady = cdx * ady;
+	let adxcdy = adx * cdy;
+	let blift = bdx * bdx + bdy * bdy;
+
+	let adxbdy = adx * bdy;
+	let bdxady = bdx * ady;
+	let clift = cdx * cdx + cdy * cdy;
+
+	let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+
+	let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+		+ (cdxady.abs() + adxcdy.abs()) * blift
+		+ (adxbdy.abs() + bdxady.abs()) * clift;
+	let errbound = in_circle_error_bound::<T>() * permanent;
+	if (det > errbound || -det > errbound) && permanent >= underflow_bound() {
+		return det;
+	}
+
+	match exact_scale(&[ax, ay, bx, by, cx, cy, dx, dy], 4){
+		Some(s) => in_circle(ax * s, ay * s, bx * s, by * s, cx * s, cy * s, dx * s, dy * s),
+		None => in_circle_exact(ax, ay, bx, by, cx, cy, dx, dy),
+	}
+}
+
+///Evaluates the in circle determinant exactly using expansion arithmetic.
+#[allow(clippy::too_many_arguments)]
+fn in_circle_exact<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T, dx: T, dy: T) -> T {
+	let adx = two_diff_expansion(ax, dx);
+	let bdx = two_diff_expansion(bx, dx);
+	let cdx = two_diff_expansion(cx, dx);
+	let ady = two_diff_expansion(ay, dy);
+	let bdy = two_diff_expansion(by, dy);
+	let cdy = two_diff_expansion(cy, dy);
+
+	let splitter = splitter::<T>();
+	let product = |e: &[T], f: &[T]| expansion_product(e, f, splitter);
+	let lift = |x: &[T], y: &[T]| expansion_sum(&product(x, x), &product(y, y));
+	let cross = |x1: &[T], y1: &[T], x2: &[T], y2: &[T]| {
+		expansion_diff(&product(x1, y2), &product(x2, y1))
+	};
+
+	let a_term = product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+	let b_term = product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+	let c_term = product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+
+	expansion_sign(&expansion_sum(&expansion_sum(&a_term, &b_term), &c_term))
+}
+
+///Returns a positive value if d lies below the plane through a, b and c, a negative value if it lies above
+///and zero if the four points are coplanar. Below is the side from which a, b and c appear clockwise.
+///Only the sign of the result is exact. Very large and very small coordinates are rescaled like in orient2d.
+#[allow(clippy::too_many_arguments)]
+pub fn orient3d<T: Float>(ax: T, ay: T, az: T, bx: T, by: T, bz: T, cx: T, cy: T, cz: T, dx: T, dy: T, dz: T) -> T {
+	let adx = ax - dx;
+	let bdx = bx - dx;
+	let cdx = cx - dx;
+	let ady = ay - dy;
+	let bdy = by - dy;
+	let cdy = cy - dy;
+	let adz = az - dz;
+	let bdz = bz - dz;
+	let cdz = cz - dz;
+
+	let bdxcdy = bdx * cdy;
+	let cdxbdy = cdx * bdy;
+
+	let cdxady = cdx * ady;
+	let adxcdy = adx * cdy;
+
+	let adxbdy = adx * bdy;
+	let bdxady = bdx * ady;
+
+	let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+
+	let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs()
+		+ (cdxady.abs() + adxcdy.abs()) * bdz.abs()
+		+ (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+	let errbound = orient3d_error_bound::<T>() * permanent;
+	if (det > errbound || -det > errbound) && permanent >= underflow_bound() {
+		return det;
+	}
+
+	match exact_scale(&[ax, ay, az, bx, by, bz, cx, cy, cz, dx, dy, dz], 3){
+		Some(s) => orient3d(ax * s, ay * s, az * s, bx * s, by * s, bz * s, cx * s, cy * s, cz * s, dx * s, dy * s, dz * s),
+		None => orient3d_exact(ax, ay, az, bx, by, bz, cx, cy, cz, dx, dy, dz),
+	}
+}
+
+///Evaluates the 3D orientation determinant exactly using expansion arithmetic.
+#[allow(clippy::too_many_arguments)]
+fn orient3d_exact<T: Float>(ax: T, ay: T, az: T, bx: T, by: T, bz: T, cx: T, cy: T, cz: T, dx: T, dy: T, dz: T) -> T {
+	let adx = two_diff_expansion(ax, dx);
+	let bdx = two_diff_expansion(bx, dx);
+	let cdx = two_diff_expansion(cx, dx);
+	let ady = two_diff_expansion(ay, dy);
+	let bdy = two_diff_expansion(by, dy);
+	let cdy = two_diff_expansion(cy, dy);
+	let adz = two_diff_expansion(az, dz);
+	let bdz = two_diff_expansion(bz, dz);
+	let cdz = two_diff_expansion(cz, dz);
+
+	let splitter = splitter::<T>();
+	let product = |e: &[T], f: &[T]| expansion_product(e, f, splitter);
+	let cross = |x1: &[T], y1: &[T], x2: &[T], y2: &[T]| {
+		expansion_diff(&product(x1, y2), &product(x2, y1))
+	};
+
+	let a_term = product(&adz, &cross(&bdx, &bdy, &cdx, &cdy));
+	let b_term = product(&bdz, &cross(&cdx, &cdy, &adx, &ady));
+	let c_term = product(&cdz, &cross(&adx, &ady, &bdx, &bdy));
+
+	expansion_sign(&expansion_sum(&expansion_sum(&a_term, &b_term), &c_term))
+}
+
+///Evaluates the orientation determinant exactly using expansion arithmetic.
+fn orient2d_exact<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T) -> T {
+	//det = ax*by - ax*cy - cx*by - ay*bx + ay*cx + cy*bx
+	let splitter = splitter::<T>();
+	let terms = [
+		two_product(ax, by, splitter),
+		two_product(-ax, cy, splitter),
+		two_product(-cx, by, splitter),
+		two_product(-ay, bx, splitter),
+		two_product(ay, cx, splitter),
+		two_product(cy, bx, splitter),
+	];
+
+	let mut expansion = Vec::with_capacity(terms.len() * 2);
+	for &(high, low) in terms.iter() {
+		expansion = grow_expansion(&expansion, low);
+		expansion = grow_expansion(&expansion, high);
+	}
+
+	expansion_sign(&expansion)
+}
+
+///Evaluates the cross product of b - a and d - c exactly using expansion arithmetic.
+#[allow(clippy::too_many_arguments)]
+fn cross_exact<T: Float>(ax: T, ay: T, bx: T, by: T, cx: T, cy: T, dx: T, dy: T) -> T {
+	//det = bx*dy - bx*cy - ax*dy + ax*cy - by*dx + by*cx + ay*dx - ay*cx
+	let splitter = splitter::<T>();
+	let terms = [
+		two_product(bx, dy, splitter),
+		two_product(-bx, cy, splitter),
+		two_product(-ax, dy, splitter),
+		two_product(ax, cy, splitter),
+		two_product(-by, dx, splitter),
+		two_product(by, cx, splitter),
+		two_product(ay, dx, splitter),
+		two_product(-ay, cx, splitter),
+	];
+
+	let mut expansion = Vec::with_capacity(terms.len() * 2);
+	for &(high, low) in terms.iter() {
+		expansion = grow_expansion(&expansion, low);
+		expansion = grow_expansion(&expansion, high);
+	}
+
+	expansion_sign(&expansion)
+}
+
+///Machine epsilon as used by Shewchuk, half of the distance from 1.0 to the next float.
+fn epsilon<T: Float>() -> T {
+	T::epsilon() / (T::one() + T::one())
+}
+
+///Smallest magnitude a product in a floating-point filter can have while its rounding error is still relative.
+fn underflow_bound<T: Float>() -> T {
+	T::min_positive_value() / epsilon::<T>()
+}
+
+///Returns a power of two that brings the largest of 'values' into the range where products of 'degree'
+///of their differences neither overflow nor become subnormal, or None if it already is, all values are zero
+///or one of them is not finite.
+fn exact_scale<T: Float>(values: &[T], degree: i32) -> Option<T> {
+	let eps = epsilon::<T>();
+	let root = T::one() / T::from(degree).unwrap();
+	let upper = T::max_value().powf(root) * eps;
+	let lower = (T::min_positive_value() / eps.powi(degree)).powf(root);
+
+	let mut largest = T::zero();
+	for value in values {
+		if !value.is_finite() {
+			return None;
+		}
+		largest = largest.max(value.abs());
+	}
+	if largest.is_zero() || (largest >= lower && largest <= upper) {
+		return None;
+	}
+
+	//the smallest change needed, so as few values as possible underflow when scaling down.
+	//Steps of epsilon get close quickly, steps of two then stop just inside the range.
+	let two = T::one() + T::one();
+	let mut scale = T::one();
+	while largest * scale > upper {
+		scale = scale * if largest * scale * eps > upper { eps } else { two.recip() };
+	}
+	while largest * scale < lower {
+		scale = scale * if largest * scale / eps < lower { eps.recip() } else { two };
+	}
+	Some(scale)
+}
+
+///Error bound for the floating-point filter of orient2d.
+fn ccw_error_bound<T: Float>() -> T {
+	let eps = epsilon::<T>();
+	let three = T::from(3).unwrap();
+	let sixteen = T::from(16).unwrap();
+	(three + sixteen * eps) * eps
+}
+
+///Error bound for the floating-point filter of orient3d.
+fn orient3d_error_bound<T: Float>() -> T {
+	let eps = epsilon::<T>();
+	let seven = T::from(7).unwrap();
+	let fifty_six = T::from(56).unwrap();
+	(seven + fifty_six * eps) * eps
+}
+
+///Error bound for the floating-point filter of in_circle.
+fn in_circle_error_bound<T: Float>() -> T {
+	let eps = epsilon::<T>();
+	let ten = T::from(10).unwrap();
+	let ninety_six = T::from(96).unwrap();
+	(ten + ninety_six * eps) * eps
+}
+
+///Constant used to split a float into two non-overlapping halves, 2^ceil(p/2) + 1 for a p bit significand.
+///The exact evaluations compute it once and pass it on to every two_product.
+fn splitter<T: Float>() -> T {
+	//epsilon is 2^(1-p), so 2/epsilon is 2^p and its square root is 2^ceil(p/2) exactly when p is even
+	let two = T::one() + T::one();
+	let even = (two / T::epsilon()).sqrt();
+	let power = if even.fract().is_zero() { even } else { (two * two / T::epsilon()).sqrt() };
+	power + T::one()
+}
+
+///Returns (x,y) where x = fl(a+b) and x + y = a + b exactly.
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+	let x = a + b;
+	let b_virtual = x - a;
+	let a_virtual = x - b_virtual;
+	let b_round = b - b_virtual;
+	let a_round = a - a_virtual;
+	(x, a_round + b_round)
+}
+
+///Splits a into a high and a low half with non-overlapping bits.
+fn split<T: Float>(a: T, splitter: T) -> (T, T) {
+	let c = splitter * a;
+	let a_big = c - a;
+	let a_high = c - a_big;
+	(a_high, a - a_high)
+}
+
+///Returns (x,y) where x = fl(a*b) and x + y = a * b exactly.
+fn two_product<T: Float>(a: T, b: T, splitter: T) -> (T, T) {
+	let x = a * b;
+	let (a_high, a_low) = split(a, splitter);
+	let (b_high, b_low) = split(b, splitter);
+	let err1 = x - (a_high * b_high);
+	let err2 = err1 - (a_low * b_high);
+	let err3 = err2 - (a_high * b_low);
+	(x, (a_low * b_low) - err3)
+}
+
+///Adds a float to a non-overlapping expansion, the result is ordered from least to most significant component.
+fn grow_expansion<T: Float>(expansion: &[T], b: T) -> Vec<T> {
+	let mut result = Vec::with_capacity(expansion.len() + 1);
+	let mut q = b;
+	for &e in expansion {
+		let (sum, error) = two_sum(q, e);
+		result.push(error);
+		q = sum;
+	}
+	result.push(q);
+	result
+}
+
+///Returns a - b as an exact expansion.
+fn two_diff_expansion<T: Float>(a: T, b: T) -> Vec<T> {
+	let (x, y) = two_sum(a, -b);
+	vec![y, x]
+}
+
+///Returns the exact sum of two expansions.
+fn expansion_sum<T: Float>(e: &[T], f: &[T]) -> Vec<T> {
+	let mut result = e.to_vec();
+	for &component in f {
+		result = grow_expansion(&result, component);
+	}
+	compress(result)
+}
+
+///Returns the exact difference of two expansions.
+fn expansion_diff<T: Float>(e: &[T], f: &[T]) -> Vec<T> {
+	let negated: Vec<T> = f.iter().map(|&c| -c).collect();
+	expansion_sum(e, &negated)
+}
+
+///Returns the exact product of two expansions.
+fn expansion_product<T: Float>(e: &[T], f: &[T], splitter: T) -> Vec<T> {
+	let mut result = Vec::new();
+	for &a in e {
+		for &b in f {
+			let (high, low) = two_product(a, b, splitter);
+			result = grow_expansion(&result, low);
+			result = grow_expansion(&result, high);
+		}
+	}
+	compress(result)
+}
+
+///Removes zero components, which keeps the expansion non-overlapping.
+fn compress<T: Float>(expansion: Vec<T>) -> Vec<T> {
+	expansion.into_iter().filter(|c| !c.is_zero()).collect()
+}
+
+///Returns the most significant non-zero component of an expansion, which carries its sign.
+fn expansion_sign<T: Float>(expansion: &[T]) -> T {
+	expansion.iter().rev().find(|e| !e.is_zero()).cloned().unwrap_or_else(T::zero)
+}
+
+
+#[cfg(test)]
+mod predicates_test {
+	use super::*;
+
+	#[test]
+	fn orient2d_test() {
+		assert!(orient2d(0.0, 0.0, 1.0, 0.0, 0.0, 1.0) > 0.0);
+		assert!(orient2d(0.0, 0.0, 0.0, 1.0, 1.0, 0.0) < 0.0);
+		assert_eq!(orient2d(0.0, 0.0, 1.0, 1.0, 2.0, 2.0), 0.0);
+		assert!(orient2d(0.0f32, 0.0, 1.0, 0.0, 0.0, 1.0) > 0.0);
+	}
+
+	#[test]
+	fn orient2d_overflow_test() {
+		let big = 1e308;
+		assert!(orient2d(-big, -big, big, -big, big, big) > 0.0);
+		assert!(orient2d(-big, -big, big, big, big, -big) < 0.0);
+		assert_eq!(orient2d(-big, -big, big, big, 0.0, 0.0), 0.0);
+		assert_eq!(orient2d(f64::MIN, 0.0, f64::MAX, 0.0, 0.0, 0.0), 0.0);
+		assert!(orient2d(f64::MIN, 0.0, f64::MAX, 0.0, 0.0, f64::MIN_POSITIVE) > 0.0);
+		assert!(orient2d(-3e38f32, 0.0, 3e38, 0.0, 0.0, -1.0) < 0.0);
+		assert!(cross(-big, -big, big, big, big, -big, -big, big) > 0.0);
+		assert_eq!(cross(f64::MIN, f64::MIN, f64::MAX, f64::MAX, 0.0, 0.0, 1.0, 1.0), 0.0);
+		assert!(cross(0.0, f64::MIN, 0.0, f64::MAX, f64::MAX, 0.0, f64::MIN, 1e-300) > 0.0);
+		assert!(orient2d(f64::NAN, 0.0, 1.0, 0.0, 0.0, 1.0).is_nan());
+	}
+
+	#[test]
+	fn orient2d_underflow_test() {
+		let tiny = 1e-200;
+		assert!(orient2d(0.0, 0.0, tiny, 0.0, 0.0, tiny) > 0.0);
+		assert!(orient2d(0.0, 0.0, 0.0, tiny, tiny, 0.0) < 0.0);
+		assert_eq!(orient2d(0.0, 0.0, tiny, tiny, 2.0 * tiny, 2.0 * tiny), 0.0);
+		assert!(orient2d(0.0, 0.0, f64::MIN_POSITIVE, 0.0, 0.0, 5e-324) > 0.0);
+		assert!(orient2d(0.0f32, 0.0, 1e-30, 0.0, 0.0, 1e-30) > 0.0);
+		assert!(cross(0.0, 0.0, tiny, 0.0, tiny, tiny, tiny, 2.0 * tiny) > 0.0);
+		assert_eq!(cross(0.0, 0.0, tiny, tiny, tiny, 0.0, 2.0 * tiny, tiny), 0.0);
+
+		//the near collinear grid of orient2d_near_collinear_test scaled by 1e-200
+		let step = 2.0f64.powi(-53);
+		for i in 0..64i32 {
+			for j in 0..64i32 {
+				let px = (0.5 + (i as f64) * step) * tiny;
+				let py = (0.5 + (j as f64) * step) * tiny;
+				let expected = (j - i).signum();
+				for &result in [orient2d(12.0 * tiny, 12.0 * tiny, 24.0 * tiny, 24.0 * tiny, px, py),
+					cross(12.0 * tiny, 12.0 * tiny, 24.0 * tiny, 24.0 * tiny, 0.0, 0.0, px, py)].iter() {
+					let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+					assert_eq!(sign, expected, "failed at i={} j={}", i, j);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn orient2d_near_collinear_test() {
+		//Points on a tiny grid around (0.5,0.5) tested against the line y = x.
+		//The exact sign is the sign of (j - i).
+		let step = 2.0f64.powi(-53);
+		for i in 0..64i32 {
+			for j in 0..64i32 {
+				let px = 0.5 + (i as f64) * step;
+				let py = 0.5 + (j as f64) * step;
+				let expected = (j - i).signum();
+				let result = orient2d(12.0, 12.0, 24.0, 24.0, px, py);
+				let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+				assert_eq!(sign, expected, "failed at i={} j={}", i, j);
+			}
+		}
+	}
+
+	#[test]
+	fn cross_test() {
+		assert!(cross(0.0, 0.0, 1.0, 0.0, 5.0, 5.0, 5.0, 6.0) > 0.0);
+		assert!(cross(0.0, 0.0, 1.0, 0.0, 5.0, 6.0, 5.0, 5.0) < 0.0);
+		assert_eq!(cross(0.0, 0.0, 1.0, 1.0, 3.0, 0.0, 5.0, 2.0), 0.0);
+
+		//d - c on a tiny grid around (3.5,3.5) tested against the direction of y = x.
+		//The exact sign is the sign of (j - i).
+		let step = 2.0f64.powi(-53);
+		for i in 0..64i32 {
+			for j in 0..64i32 {
+				let px = 0.5 + (i as f64) * step;
+				let py = 0.5 + (j as f64) * step;
+				let expected = (j - i).signum();
+				let result = cross(12.0, 12.0, 24.0, 24.0, -3.0, -3.0, px, py);
+				let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+				assert_eq!(sign, expected, "failed at i={} j={}", i, j);
+			}
+		}
+	}
+
+	#[test]
+	fn in_circle_test() {
+		assert!(in_circle(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.25, 0.25) > 0.0);
+		assert!(in_circle(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 2.0) < 0.0);
+		assert_eq!(in_circle(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0), 0.0);
+		assert!(in_circle(0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.25, 0.25) < 0.0);
+	}
+
+	#[test]
+	fn in_circle_near_cocircular_test() {
+		//Points one ulp away from the unit square's circumcircle.
+		let step = 2.0f64.powi(-52);
+		for i in -8..=8i32 {
+			let x = 1.0 + (i as f64) * step;
+			for &result in [in_circle(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, x, 1.0), in_circle_exact(0.0, 0.0, 1.0, 0.0, 0.0, 1.0, x, 1.0)].iter() {
+				let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+				assert_eq!(sign, -i.signum(), "failed at i={}", i);
+			}
+		}
+	}
+
+	#[test]
+	fn in_circle_underflow_test() {
+		let tiny = 1e-200;
+		assert!(in_circle(0.0, 0.0, tiny, 0.0, 0.0, tiny, 0.25 * tiny, 0.25 * tiny) > 0.0);
+		assert!(in_circle(0.0, 0.0, tiny, 0.0, 0.0, tiny, 2.0 * tiny, 2.0 * tiny) < 0.0);
+		assert_eq!(in_circle(0.0, 0.0, tiny, 0.0, 0.0, tiny, tiny, tiny), 0.0);
+
+		let step = 2.0f64.powi(-52);
+		for i in -8..=8i32 {
+			let x = (1.0 + (i as f64) * step) * tiny;
+			let sign = in_circle(0.0, 0.0, tiny, 0.0, 0.0, tiny, x, tiny);
+			let sign = if sign > 0.0 { 1 } else if sign < 0.0 { -1 } else { 0 };
+			assert_eq!(sign, -i.signum(), "failed at i={}", i);
+		}
+	}
+
+	#[test]
+	fn in_circle_overflow_test() {
+		let big = 1e300;
+		assert!(in_circle(0.0, 0.0, big, 0.0, 0.0, big, 0.25 * big, 0.25 * big) > 0.0);
+		assert!(in_circle(0.0, 0.0, big, 0.0, 0.0, big, 2.0 * big, 2.0 * big) < 0.0);
+		assert_eq!(in_circle(0.0, 0.0, big, 0.0, 0.0, big, big, big), 0.0);
+	}
+
+	#[test]
+	fn orient3d_test() {
+		//a, b and c are counter-clockwise seen from +z
+		assert!(orient3d(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0) > 0.0);
+		assert!(orient3d(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0) < 0.0);
+		assert_eq!(orient3d(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.3, 0.3, 0.0), 0.0);
+	}
+
+	#[test]
+	fn orient3d_near_coplanar_test() {
+		//Points near the plane x + y + z = 1.5, the exact sign is the sign of the offset.
+		let step = 2.0f64.powi(-53);
+		for i in -16..=16i32 {
+			let z = 0.5 + (i as f64) * step;
+			for &result in [orient3d(1.5, 0.0, 0.0, 0.0, 1.5, 0.0, 0.0, 0.0, 1.5, 0.5, 0.5, z),
+				orient3d_exact(1.5, 0.0, 0.0, 0.0, 1.5, 0.0, 0.0, 0.0, 1.5, 0.5, 0.5, z)].iter() {
+				let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+				assert_eq!(sign, -i.signum(), "failed at i={}", i);
+			}
+		}
+	}
+
+	#[test]
+	fn orient3d_underflow_test() {
+		let tiny = 1e-200;
+		assert!(orient3d(0.0, 0.0, 0.0, tiny, 0.0, 0.0, 0.0, tiny, 0.0, 0.0, 0.0, -tiny) > 0.0);
+		assert!(orient3d(0.0, 0.0, 0.0, tiny, 0.0, 0.0, 0.0, tiny, 0.0, 0.0, 0.0, tiny) < 0.0);
+		assert_eq!(orient3d(0.0, 0.0, 0.0, tiny, 0.0, 0.0, 0.0, tiny, 0.0, 0.3 * tiny, 0.3 * tiny, 0.0), 0.0);
+
+		let step = 2.0f64.powi(-53);
+		for i in -16..=16i32 {
+			let z = (0.5 + (i as f64) * step) * tiny;
+			let result = orient3d(1.5 * tiny, 0.0, 0.0, 0.0, 1.5 * tiny, 0.0, 0.0, 0.0, 1.5 * tiny, 0.5 * tiny, 0.5 * tiny, z);
+			let sign = if result > 0.0 { 1 } else if result < 0.0 { -1 } else { 0 };
+			assert_eq!(sign, -i.signum(), "failed at i={}", i);
+		}
+	}
+
+	#[test]
+	fn splitter_test() {
+		assert_eq!(splitter::<f64>(), 134_217_729.0);
+		assert_eq!(splitter::<f32>(), 4097.0);
+	}
+
+	#[test]
+	fn two_product_test() {
+		let a = 1.0 + 2.0f64.powi(-30);
+		let (x, y) = two_product(a, a, splitter::<f64>());
+		assert_eq!(x, 1.0 + 2.0f64.powi(-29));
+		assert_eq!(y, 2.0f64.powi(-60));
+	}
+}
@@ -1,190 +1,1412 @@
-use num_traits::Float;
-use num_traits::Zero;
 use std::cmp::Ordering;
+use std::ops::{Add,Sub,Mul,Neg,Deref};
+use std::hash::{Hash,Hasher};
+use crate::predicates::in_circle;
+use crate::coordinate::{Coordinate,FloatCoordinate};
+use crate::tolerance::{Tolerance,ApproxEq};
+use crate::angle::Angle;
+use crate::point2d_like::Point2DLike;
 
 ///2D Point
 #[derive(Copy,Clone,Debug,PartialEq,Eq)]
-pub struct Point2D<T: Float+Zero>{
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2D<T: Coordinate>{
 	///Point's x position
 	pub x : T,
 	///Point's y position
 	pub y : T,
 }
 
-impl<T: Float+Zero> Point2D<T >{
+impl<T: Coordinate> Point2D<T >{
 
 	///Creates a Point.
 	pub fn new(x:T,y:T) -> Self{
 		Point2D{x,y}
 	}
 
-	///Returns the rotational direction of the points (self,p1,p1).
+	///Returns the rotational direction of the points (self,p1,p2).
+	///Uses the exact orientation predicate of the coordinate type, so nearly collinear points are classified correctly.
 	pub fn turn_direction(&self, p1: &Point2D<T>,p2: &Point2D<T>) -> TurnDirection{
-		let line1 = (p1.x -self.x, p1.y -self.y);
-		let line2 = (p2.x -self.x, p2.y -self.y);
+		match T::orient2d(&self.x, &self.y, &p1.x, &p1.y, &p2.x, &p2.y){
+			Ordering::Less    => TurnDirection::RightTurn,
+			Ordering::Greater => TurnDirection::LeftTurn,
+			Ordering::Equal   => TurnDirection::NoTurn,
+		}
+	}
+
+	///Partial comparision function ordered by x value then by y in case of ties.
+	pub fn x_then_y_partial_cmp(&self,other: &Point2D<T>) -> Option<Ordering> {
+		if self.x != other.x{
+			self.x.partial_cmp(&other.x)
+		}
+		else{
+			self.y.partial_cmp(&other.y)
+		}
+	}
+
+	///Comparision function ordered by x value then by y in case of ties.
+	///Incomparable coordinates such as NaN compare as Greater, use TotalPoint2D for a total order.
+	pub fn x_then_y_cmp(&self,other: &Point2D<T>) -> Ordering {
+		if let Some(ord)= self.x_then_y_partial_cmp(other) {
+			ord
+		}
+		else{
+			Ordering::Greater
+		}
+	}
+
+	///Partial comparision function ordered polar cordinate in relation to rotational point.
+	pub fn rotation_point_cmp(&self,other: &Point2D<T>,rotational_point: &Point2D<T>) -> Option<Ordering> {
+		match rotational_point.turn_direction(self,other){
+			TurnDirection::LeftTurn => Some(Ordering::Greater),
+			TurnDirection::RightTurn  => Some(Ordering::Less),
+			TurnDirection::NoTurn    => {
+				self.x_then_y_partial_cmp(other)
+			},
+		}
+	}
+
+	///Compares the counter-clockwise angles of self and other around 'pivot', measured from the positive x direction.
+	///Angles are in [0, 2π), points in the same direction compare Equal and the pivot itself comes first.
+	///Uses only comparisons and exact turn tests, so unlike rotation_point_cmp it is a consistent order around the whole pivot.
+	pub fn pseudo_angle_cmp(&self,other: &Point2D<T>,pivot: &Point2D<T>) -> Ordering {
+		//upper half plane including the positive x direction
+		let half = |p: &Point2D<T>| if p.y > pivot.y || (p.y == pivot.y && p.x > pivot.x) {0} else {1};
+		self.angle_cmp_by_half(other,pivot,half)
+	}
+
+	///Compares the counter-clockwise angles of self and other around 'pivot', measured from the direction of 'reference'.
+	///Angles are in [0, 2π), points in the same direction compare Equal and the pivot itself comes first.
+	///'reference' must be different from 'pivot'.
+	pub fn angle_cmp_from(&self,other: &Point2D<T>,pivot: &Point2D<T>,reference: &Point2D<T>) -> Ordering {
+		let same_direction = |p: &Point2D<T>|
+			p.x.partial_cmp(&pivot.x) == reference.x.partial_cmp(&pivot.x) &&
+			p.y.partial_cmp(&pivot.y) == reference.y.partial_cmp(&pivot.y);
+		let half = |p: &Point2D<T>| match pivot.turn_direction(reference,p){
+			TurnDirection::LeftTurn => 0,
+			TurnDirection::RightTurn => 1,
+			TurnDirection::NoTurn => if same_direction(p) {0} else {1},
+		};
+		self.angle_cmp_by_half(other,pivot,half)
+	}
+
+	///Angle comparison given a split of the plane around 'pivot' into two halves of less than a half turn each.
+	fn angle_cmp_by_half<F>(&self,other: &Point2D<T>,pivot: &Point2D<T>,half: F) -> Ordering where
+		F : Fn(&Point2D<T>) -> u8{
+
+		match (self == pivot,other == pivot){
+			(true,true)   => return Ordering::Equal,
+			(true,false)  => return Ordering::Less,
+			(false,true)  => return Ordering::Greater,
+			(false,false) => (),
+		}
+
+		half(self).cmp(&half(other)).then_with(|| match pivot.turn_direction(self,other){
+			TurnDirection::LeftTurn  => Ordering::Less,
+			TurnDirection::RightTurn => Ordering::Greater,
+			TurnDirection::NoTurn    => Ordering::Equal,
+		})
+	}
+}
+
+impl<T: FloatCoordinate> Point2D<T >{
+
+	///Returns the position of self relative to the circle through p1, p2 and p3, in any order.
+	///Uses the exact in_circle predicate. If p1, p2 and p3 are collinear the circle is degenerate,
+	///points on their line are reported as OnCircle and every other point as Outside.
+	pub fn circle_position(&self, p1: &Point2D<T>,p2: &Point2D<T>,p3: &Point2D<T>) -> CirclePosition{
+		let det = in_circle(p1.x, p1.y, p2.x, p2.y, p3.x, p3.y, self.x, self.y);
+		let det = match p1.turn_direction(p2,p3){
+			TurnDirection::LeftTurn => det,
+			TurnDirection::RightTurn => -det,
+			TurnDirection::NoTurn => return if p1.turn_direction(p2,self) == TurnDirection::NoTurn &&
+				p1.turn_direction(p3,self) == TurnDirection::NoTurn {CirclePosition::OnCircle} else {CirclePosition::Outside},
+		};
+
+		if det > T::zero(){
+			CirclePosition::Inside
+		}
+		else if det < T::zero(){
+			CirclePosition::Outside
+		}
+		else{
+			CirclePosition::OnCircle
+		}
+	}
+
+	///Returns the squared euclidean distance to 'other'.
+	pub fn distance_squared(&self, other: &Point2D<T>) -> T{
+		(*other - *self).norm_squared()
+	}
+
+	///Returns the euclidean distance to 'other'.
+	pub fn distance(&self, other: &Point2D<T>) -> T{
+		(*other - *self).norm()
+	}
+
+	///Returns the polar coordinates (radius,angle) of self around 'origin', with the angle in (-π, π].
+	pub fn to_polar(&self, origin: &Point2D<T>) -> (T,Angle<T>){
+		let offset = *self - *origin;
+		(offset.norm(),offset.angle())
+	}
+
+	///Creates the point at 'radius' from 'origin' in the direction 'angle'.
+	pub fn from_polar(origin: &Point2D<T>, radius: T, angle: Angle<T>) -> Point2D<T>{
+		*origin + Vector2D::from_polar(radius,angle)
+	}
+
+	///Returns the rotational direction of the points (self,p1,p2), reporting NoTurn when the
+	///points are within 'tolerance' of a common line.
+	///The distance checked is the one from the nearer of p1 and p2 to the line through self and the farther one.
+	pub fn turn_direction_with_tolerance(&self, p1: &Point2D<T>,p2: &Point2D<T>,tolerance: &Tolerance<T>) -> TurnDirection{
+		let turn = self.turn_direction(p1,p2);
+		if turn == TurnDirection::NoTurn{
+			return turn;
+		}
+
+		let (v1,v2) = (*p1 - *self,*p2 - *self);
+		let (far,near) = if v1.norm_squared() >= v2.norm_squared() {(v1,v2)} else {(v2,v1)};
+		let distance = far.cross(&near) / far.norm();
+
+		if tolerance.is_zero(distance,magnitude(&[self,p1,p2])){
+			TurnDirection::NoTurn
+		}
+		else{
+			turn
+		}
+	}
+}
+
+///Returns the largest absolute coordinate of the points, the scale used for relative tolerances.
+fn magnitude<T: FloatCoordinate>(points: &[&Point2D<T>]) -> T{
+	points.iter().fold(T::zero(),|max,p| max.max(p.x.abs()).max(p.y.abs()))
+}
+
+///Point2D wrapper with a total order and a consistent hash, usable as a map key or in a set.
+///Points are ordered by x then y. -0.0 equals 0.0, every NaN equals every other NaN and is greater than any number.
+#[derive(Copy,Clone,Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TotalPoint2D<T: FloatCoordinate>(pub Point2D<T>);
+
+impl<T: FloatCoordinate> TotalPoint2D<T >{
+	///Wraps a point.
+	pub fn new(point: Point2D<T>) -> Self{
+		TotalPoint2D(point)
+	}
+
+	///Returns the wrapped point.
+	pub fn point(&self) -> Point2D<T>{
+		self.0
+	}
+}
+
+///Total order on floats where NaN is the largest value and -0.0 equals 0.0.
+fn total_cmp<T: FloatCoordinate>(a: T, b: T) -> Ordering{
+	match (a.is_nan(),b.is_nan()){
+		(true,true)   => Ordering::Equal,
+		(true,false)  => Ordering::Greater,
+		(false,true)  => Ordering::Less,
+		(false,false) => a.partial_cmp(&b).unwrap(),
+	}
+}
+
+///Hashes a float consistently with total_cmp.
+fn total_hash<T: FloatCoordinate, H: Hasher>(value: T, state: &mut H){
+	if value.is_nan(){
+		state.write_u8(2);
+	}
+	else if value.is_zero(){
+		state.write_u8(1);
+	}
+	else{
+		state.write_u8(0);
+		value.integer_decode().hash(state);
+	}
+}
+
+impl<T: FloatCoordinate> Ord for TotalPoint2D<T> {
+	fn cmp(&self, other: &TotalPoint2D<T>) -> Ordering {
+		total_cmp(self.0.x,other.0.x).then_with(|| total_cmp(self.0.y,other.0.y))
+	}
+}
+
+impl<T: FloatCoordinate> PartialOrd for TotalPoint2D<T> {
+	fn partial_cmp(&self, other: &TotalPoint2D<T>) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: FloatCoordinate> PartialEq for TotalPoint2D<T> {
+	fn eq(&self, other: &TotalPoint2D<T>) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+
+impl<T: FloatCoordinate> Eq for TotalPoint2D<T> {
+}
+
+impl<T: FloatCoordinate> Hash for TotalPoint2D<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		total_hash(self.0.x,state);
+		total_hash(self.0.y,state);
+	}
+}
+
+impl<T: FloatCoordinate> From<Point2D<T>> for TotalPoint2D<T> {
+	fn from(point: Point2D<T>) -> Self {
+		TotalPoint2D(point)
+	}
+}
+
+///2D Vector
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2D<T: Coordinate>{
+	///Vector's x component
+	pub x : T,
+	///Vector's y component
+	pub y : T,
+}
+
+impl<T: Coordinate> Vector2D<T >{
+
+	///Creates a Vector.
+	pub fn new(x:T,y:T) -> Self{
+		Vector2D{x,y}
+	}
+
+	///Creates the zero Vector.
+	pub fn zero() -> Self{
+		Vector2D{x: T::zero(),y: T::zero()}
+	}
+
+	///Returns the dot product of the vectors.
+	pub fn dot(&self, other: &Vector2D<T>) -> T{
+		self.x.clone() * other.x.clone() + self.y.clone() * other.y.clone()
+	}
+
+	///Returns the z component of the cross product of the vectors.
+	///Positive if other is counter-clockwise from self.
+	pub fn cross(&self, other: &Vector2D<T>) -> T{
+		self.x.clone() * other.y.clone() - self.y.clone() * other.x.clone()
+	}
+
+	///Returns the squared length of the vector.
+	pub fn norm_squared(&self) -> T{
+		self.dot(self)
+	}
+
+	///Returns the vector rotated 90 degrees counter-clockwise.
+	pub fn perp(&self) -> Vector2D<T>{
+		Vector2D::new(-self.y.clone(), self.x.clone())
+	}
+}
+
+impl<T: FloatCoordinate> Vector2D<T >{
+
+	///Returns the length of the vector.
+	pub fn norm(&self) -> T{
+		self.x.hypot(self.y)
+	}
+
+	///Returns the vector scaled to unit length, or None for the zero vector.
+	pub fn normalize(&self) -> Option<Vector2D<T>>{
+		let norm = self.norm();
+		if norm.is_zero(){
+			None
+		}
+		else{
+			Some(Vector2D::new(self.x / norm, self.y / norm))
+		}
+	}
+
+	///Creates the vector of length 'radius' in the direction 'angle'.
+	pub fn from_polar(radius: T, angle: Angle<T>) -> Self{
+		let (sin,cos) = angle.radians.sin_cos();
+		Vector2D::new(radius * cos,radius * sin)
+	}
+
+	///Returns the direction of the vector measured counter-clockwise from the positive x axis, in (-π, π].
+	pub fn angle(&self) -> Angle<T>{
+		Angle::from_radians(self.y.atan2(self.x))
+	}
+
+	///Returns the rotation from self to other, counter-clockwise positive, in (-π, π].
+	pub fn signed_angle_to(&self, other: &Vector2D<T>) -> Angle<T>{
+		Angle::from_radians(self.cross(other).atan2(self.dot(other)))
+	}
+
+	///Returns the angle between the vectors, in [0, π].
+	pub fn angle_to(&self, other: &Vector2D<T>) -> Angle<T>{
+		Angle::from_radians(self.signed_angle_to(other).radians.abs())
+	}
+}
+
+impl<T: Coordinate> Add for Vector2D<T> {
+	type Output = Vector2D<T>;
+	fn add(self, other: Vector2D<T>) -> Vector2D<T> {
+		Vector2D::new(self.x + other.x, self.y + other.y)
+	}
+}
+
+impl<T: Coordinate> Sub for Vector2D<T> {
+	type Output = Vector2D<T>;
+	fn sub(self, other: Vector2D<T>) -> Vector2D<T> {
+		Vector2D::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+impl<T: Coordinate> Mul<T> for Vector2D<T> {
+	type Output = Vector2D<T>;
+	fn mul(self, scalar: T) -> Vector2D<T> {
+		Vector2D::new(self.x * scalar.clone(), self.y * scalar)
+	}
+}
+
+impl<T: Coordinate> Neg for Vector2D<T> {
+	type Output = Vector2D<T>;
+	fn neg(self) -> Vector2D<T> {
+		Vector2D::new(-self.x, -self.y)
+	}
+}
+
+impl<T: Coordinate> Sub for Point2D<T> {
+	type Output = Vector2D<T>;
+	fn sub(self, other: Point2D<T>) -> Vector2D<T> {
+		Vector2D::new(self.x - other.x, self.y - other.y)
+	}
+}
+
+impl<T: Coordinate> Add<Vector2D<T>> for Point2D<T> {
+	type Output = Point2D<T>;
+	fn add(self, vector: Vector2D<T>) -> Point2D<T> {
+		Point2D::new(self.x + vector.x, self.y + vector.y)
+	}
+}
+
+impl<T: Coordinate> Sub<Vector2D<T>> for Point2D<T> {
+	type Output = Point2D<T>;
+	fn sub(self, vector: Vector2D<T>) -> Point2D<T> {
+		Point2D::new(self.x - vector.x, self.y - vector.y)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Point2D<T> {
+	fn approx_eq(&self, other: &Point2D<T>, tolerance: &Tolerance<T>) -> bool {
+		tolerance.eq(self.x,other.x) && tolerance.eq(self.y,other.y)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Vector2D<T> {
+	fn approx_eq(&self, other: &Vector2D<T>, tolerance: &Tolerance<T>) -> bool {
+		tolerance.eq(self.x,other.x) && tolerance.eq(self.y,other.y)
+	}
+}
+
+///2D Line
+#[derive(Copy,Clone,Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line2D<T: Coordinate>{
+	///Point 1
+	pub p1 : Point2D<T>,
+	///Point 2
+	pub p2 : Point2D<T>,
+}
+
+impl<T: Coordinate> Line2D<T >{
+	///Creates a 2D Line from p1 to p2.
+	pub fn new(p1:Point2D<T>,p2:Point2D<T>) -> Self{
+		Line2D{p1,p2}
+	}
+
+	///Returns the smallest axis aligned rectangle containing the line.
+	pub fn bounding_box(&self) -> Rect2D<T>{
+		Rect2D::new(self.p1.clone(),self.p2.clone())
+	}
+
+	///returns true if 'point' is on this line
+	pub fn contains_point(&self, point: &Point2D<T>) -> bool{
+
+		if !self.bounding_box().contains_point(point){
+			return false;
+		}
+
+		self.p1.turn_direction(&self.p2,point) == TurnDirection::NoTurn
+	}
+
+	///Returns true if the lines intersect.
+	pub fn intersects_with_line(&self, other: &Line2D<T>) -> bool{
+
+		if !self.bounding_box().intersects_rect(&other.bounding_box()){
+			return false;
+		}
+
+		((self.p1.turn_direction(&self.p2,&other.p1) !=  self.p1.turn_direction(&self.p2,&other.p2)) &&
+		(other.p1.turn_direction(&other.p2,&self.p1) != other.p1.turn_direction(&other.p2,&self.p2))) ||
+		((self.p1.turn_direction(&self.p2,&other.p1) == TurnDirection::NoTurn) && (self.p1.turn_direction(&self.p2,&other.p2) == TurnDirection::NoTurn)&&
+		(self.contains_point(&other.p1) || self.contains_point(&other.p2) || other.contains_point(&self.p1)))
+
+	}
+}
+
+impl<T: FloatCoordinate> Line2D<T >{
+
+	///Returns the vector from p1 to p2.
+	pub fn direction(&self) -> Vector2D<T>{
+		self.p2 - self.p1
+	}
+
+	///Returns the length of the line.
+	pub fn length(&self) -> T{
+		self.p1.distance(&self.p2)
+	}
+
+	///Returns the parameter t of the projection of 'point' onto the line through p1 and p2,
+	///where t = 0 is p1 and t = 1 is p2. Returns 0 for a zero length line.
+	pub fn projection_parameter(&self, point: &Point2D<T>) -> T{
+		let direction = self.direction();
+		let length_squared = direction.norm_squared();
+		if length_squared.is_zero(){
+			T::zero()
+		}
+		else{
+			(*point - self.p1).dot(&direction) / length_squared
+		}
+	}
+
+	///Returns the point on the line closest to 'point'.
+	pub fn closest_point(&self, point: &Point2D<T>) -> Point2D<T>{
+		let t = self.projection_parameter(point).max(T::zero()).min(T::one());
+		self.p1 + self.direction() * t
+	}
+
+	///Returns the distance from 'point' to the closest point on the line.
+	pub fn distance_to_point(&self, point: &Point2D<T>) -> T{
+		point.distance(&self.closest_point(point))
+	}
+
+	///Returns the distance between the closest points of the lines, zero if they intersect.
+	pub fn distance_to_segment(&self, other: &Line2D<T>) -> T{
+		if self.intersection(other) != SegmentIntersection::None{
+			return T::zero();
+		}
+
+		self.distance_to_point(&other.p1)
+			.min(self.distance_to_point(&other.p2))
+			.min(other.distance_to_point(&self.p1))
+			.min(other.distance_to_point(&self.p2))
+	}
+
+	///Returns true if 'point' is within 'tolerance' of this line.
+	pub fn contains_point_with_tolerance(&self, point: &Point2D<T>, tolerance: &Tolerance<T>) -> bool{
+		tolerance.is_zero(self.distance_to_point(point),magnitude(&[&self.p1,&self.p2,point]))
+	}
+
+	///Returns true if the lines intersect or come within 'tolerance' of each other.
+	pub fn intersects_with_line_with_tolerance(&self, other: &Line2D<T>, tolerance: &Tolerance<T>) -> bool{
+		self.intersection_with_tolerance(other,tolerance) != SegmentIntersection::None
+	}
+
+	///Returns how the lines intersect, as nothing, a single point or an overlapping segment.
+	///Orientation decisions use the exact orient2d predicate, only the crossing point itself is rounded.
+	pub fn intersection(&self, other: &Line2D<T>) -> SegmentIntersection<T>{
+		self.intersection_impl(other,None)
+	}
+
+	///Returns how the lines intersect, treating points within 'tolerance' of a line as on it.
+	///Segments that end within tolerance of each other meet at the endpoint.
+	pub fn intersection_with_tolerance(&self, other: &Line2D<T>, tolerance: &Tolerance<T>) -> SegmentIntersection<T>{
+		self.intersection_impl(other,Some(tolerance))
+	}
+
+	///Shared implementation of intersection, exact when no tolerance is given.
+	fn intersection_impl(&self, other: &Line2D<T>, tolerance: Option<&Tolerance<T>>) -> SegmentIntersection<T>{
+
+		let same = |a: &Point2D<T>,b: &Point2D<T>| match tolerance{
+			Some(tolerance) => a.approx_eq(b,tolerance),
+			None => a == b,
+		};
+		let turn = |a: &Point2D<T>,b: &Point2D<T>,c: &Point2D<T>| match tolerance{
+			Some(tolerance) => a.turn_direction_with_tolerance(b,c,tolerance),
+			None => a.turn_direction(b,c),
+		};
+		let contains = |line: &Line2D<T>,p: &Point2D<T>| match tolerance{
+			Some(tolerance) => line.contains_point_with_tolerance(p,tolerance),
+			None => line.contains_point(p),
+		};
+
+		let self_degenerate = same(&self.p1,&self.p2);
+		let other_degenerate = same(&other.p1,&other.p2);
+
+		if self_degenerate && other_degenerate{
+			return if same(&self.p1,&other.p1) {SegmentIntersection::Point(self.p1)} else {SegmentIntersection::None};
+		}
+		if self_degenerate{
+			return if contains(other,&self.p1) {SegmentIntersection::Point(self.p1)} else {SegmentIntersection::None};
+		}
+		if other_degenerate{
+			return if contains(self,&other.p1) {SegmentIntersection::Point(other.p1)} else {SegmentIntersection::None};
+		}
+
+		let o1 = turn(&self.p1,&self.p2,&other.p1);
+		let o2 = turn(&self.p1,&self.p2,&other.p2);
+		let o3 = turn(&other.p1,&other.p2,&self.p1);
+		let o4 = turn(&other.p1,&other.p2,&self.p2);
+
+		if o1 == TurnDirection::NoTurn && o2 == TurnDirection::NoTurn{
+			return self.collinear_intersection(other,same);
+		}
+
+		if o1 == o2 || o3 == o4{
+			return SegmentIntersection::None;
+		}
+
+		if o1 == TurnDirection::NoTurn{
+			SegmentIntersection::Point(other.p1)
+		}
+		else if o2 == TurnDirection::NoTurn{
+			SegmentIntersection::Point(other.p2)
+		}
+		else if o3 == TurnDirection::NoTurn{
+			SegmentIntersection::Point(self.p1)
+		}
+		else if o4 == TurnDirection::NoTurn{
+			SegmentIntersection::Point(self.p2)
+		}
+		else{
+			let d_self = self.direction();
+			let d_other = other.direction();
+			let t = (other.p1 - self.p1).cross(&d_other) / d_self.cross(&d_other);
+			let t = t.max(T::zero()).min(T::one());
+
+			SegmentIntersection::Point(self.p1 + d_self * t)
+		}
+	}
+
+	///Intersection of two collinear non degenerate segments.
+	///The result is built from the original endpoints ordered along self's direction.
+	fn collinear_intersection<F>(&self, other: &Line2D<T>, same: F) -> SegmentIntersection<T> where
+		F : Fn(&Point2D<T>,&Point2D<T>) -> bool{
+		let direction = self.direction();
+		let key = |p: &Point2D<T>| (*p - self.p1).dot(&direction);
+
+		let (other_low,other_high) = if key(&other.p1) <= key(&other.p2) {(other.p1,other.p2)} else {(other.p2,other.p1)};
+
+		let start = if key(&other_low) > T::zero() {other_low} else {self.p1};
+		let end   = if key(&other_high) < direction.norm_squared() {other_high} else {self.p2};
+
+		let (start_key,end_key) = (key(&start),key(&end));
+		if same(&start,&end){
+			SegmentIntersection::Point(start)
+		}
+		else if start_key > end_key{
+			SegmentIntersection::None
+		}
+		else if start_key == end_key{
+			SegmentIntersection::Point(start)
+		}
+		else{
+			SegmentIntersection::Overlap(Line2D::new(start,end))
+		}
+	}
+
+	///Returns the intersection point between 2 lines, or None if they don't intersect.
+	///Overlapping lines only report one shared point, use intersection to get the overlap.
+	///The point is self.p1 if it is inside other, else self.p2 if it is on other, else the start of the overlap.
+	pub fn intersection_point(&self, other: &Line2D<T>) -> Option<Point2D<T>>{
+		match self.intersection(other){
+			SegmentIntersection::None => None,
+			SegmentIntersection::Point(point) => Some(point),
+			SegmentIntersection::Overlap(_) if other.contains_point(&self.p1) && self.p1 != other.p1 && self.p1 != other.p2 => Some(self.p1),
+			SegmentIntersection::Overlap(_) if other.contains_point(&self.p2) => Some(self.p2),
+			SegmentIntersection::Overlap(line) => Some(line.p1),
+		}
+	}
+
+	///Returns the intersection point between 2 lines within 'tolerance', or None if they don't intersect.
+	///Overlapping lines only report one shared point, use intersection_with_tolerance to get the overlap.
+	pub fn intersection_point_with_tolerance(&self, other: &Line2D<T>, tolerance: &Tolerance<T>) -> Option<Point2D<T>>{
+		match self.intersection_with_tolerance(other,tolerance){
+			SegmentIntersection::None => None,
+			SegmentIntersection::Point(point) => Some(point),
+			SegmentIntersection::Overlap(line) => Some(line.p1),
+		}
+	}
+
+}
+
+
+impl<T:FloatCoordinate> Ord for Line2D<T> {
+    fn cmp(&self, other: &Line2D<T>) -> Ordering {
+       match self.p1.x_then_y_cmp(&other.p1){
+		   Ordering::Greater => Ordering::Greater,
+		   Ordering::Less => Ordering::Less,
+		   Ordering::Equal => self.p2.x_then_y_cmp(&other.p2),
+	   }
+    }
+}
+
+impl<T:FloatCoordinate> PartialOrd for Line2D<T> {
+    fn partial_cmp(&self, other: &Line2D<T>) -> Option<Ordering> {
+		Some(self.cmp(other))
+    }
+}
+
+impl<T:FloatCoordinate> PartialEq for Line2D<T> {
+    fn eq(&self, other: &Line2D<T>) -> bool {
+		(self.p1 == other.p1 && self.p2 == other.p2)|| (self.p2 == other.p1 && self.p1 == other.p2)
+    }
+}
+impl<T:FloatCoordinate> Eq for Line2D<T> {
+}
+
+impl<P> From<(P,P)> for Line2D<P::Coordinate> where P: Point2DLike, P::Coordinate: FloatCoordinate {
+	fn from((p1,p2): (P,P)) -> Self {
+		Line2D::new(p1.to_point(),p2.to_point())
+	}
+}
+
+
+///Infinite 2D Line passing through two points.
+#[derive(Copy,Clone,Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InfiniteLine2D<T: FloatCoordinate>{
+	///Point 1
+	pub p1 : Point2D<T>,
+	///Point 2, must be different from p1
+	pub p2 : Point2D<T>,
+}
+
+impl<T: FloatCoordinate> InfiniteLine2D<T >{
+	///Creates an infinite line through p1 and p2.
+	pub fn new(p1:Point2D<T>,p2:Point2D<T>) -> Self{
+		InfiniteLine2D{p1,p2}
+	}
+
+	///Creates an infinite line through point with the given direction.
+	pub fn from_direction(point:Point2D<T>,direction:Vector2D<T>) -> Self{
+		InfiniteLine2D{p1: point,p2: point + direction}
+	}
+
+	///Returns the vector from p1 to p2.
+	pub fn direction(&self) -> Vector2D<T>{
+		self.p2 - self.p1
+	}
+
+	///Returns which side of the line 'point' is on, looking along the direction of the line.
+	pub fn side(&self, point: &Point2D<T>) -> TurnDirection{
+		self.p1.turn_direction(&self.p2,point)
+	}
+
+	///Returns the orthogonal projection of 'point' onto the line.
+	pub fn project_point(&self, point: &Point2D<T>) -> Point2D<T>{
+		let direction = self.direction();
+		let t = (*point - self.p1).dot(&direction) / direction.norm_squared();
+		self.p1 + direction * t
+	}
+
+	///Returns the intersection with another infinite line.
+	pub fn intersection_with_line(&self, other: &InfiniteLine2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.p1,self.p2,Extent::Line),(other.p1,other.p2,Extent::Line))
+	}
+
+	///Returns the intersection with a ray.
+	pub fn intersection_with_ray(&self, ray: &Ray2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.p1,self.p2,Extent::Line),(ray.origin,ray.through,Extent::Ray))
+	}
+
+	///Returns the intersection with a line segment.
+	pub fn intersection_with_segment(&self, segment: &Line2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.p1,self.p2,Extent::Line),(segment.p1,segment.p2,Extent::Segment))
+	}
+}
+
+///2D Ray starting at an origin and passing through a second point.
+#[derive(Copy,Clone,Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray2D<T: FloatCoordinate>{
+	///Start of the ray
+	pub origin : Point2D<T>,
+	///Point the ray passes through, must be different from origin
+	pub through : Point2D<T>,
+}
+
+impl<T: FloatCoordinate> Ray2D<T >{
+	///Creates a ray starting at origin and passing through 'through'.
+	pub fn new(origin:Point2D<T>,through:Point2D<T>) -> Self{
+		Ray2D{origin,through}
+	}
+
+	///Creates a ray starting at origin heading in the given direction.
+	pub fn from_direction(origin:Point2D<T>,direction:Vector2D<T>) -> Self{
+		Ray2D{origin,through: origin + direction}
+	}
+
+	///Returns the vector from origin to through.
+	pub fn direction(&self) -> Vector2D<T>{
+		self.through - self.origin
+	}
+
+	///Returns which side of the ray's supporting line 'point' is on, looking along the ray.
+	pub fn side(&self, point: &Point2D<T>) -> TurnDirection{
+		self.origin.turn_direction(&self.through,point)
+	}
+
+	///Returns the closest point on the ray to 'point'.
+	///Points behind the origin project onto the origin.
+	pub fn project_point(&self, point: &Point2D<T>) -> Point2D<T>{
+		let direction = self.direction();
+		let t = (*point - self.origin).dot(&direction) / direction.norm_squared();
+		self.origin + direction * t.max(T::zero())
+	}
+
+	///Returns the intersection with an infinite line.
+	pub fn intersection_with_line(&self, line: &InfiniteLine2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.origin,self.through,Extent::Ray),(line.p1,line.p2,Extent::Line))
+	}
+
+	///Returns the intersection with another ray.
+	pub fn intersection_with_ray(&self, other: &Ray2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.origin,self.through,Extent::Ray),(other.origin,other.through,Extent::Ray))
+	}
+
+	///Returns the intersection with a line segment.
+	pub fn intersection_with_segment(&self, segment: &Line2D<T>) -> LinearIntersection<T>{
+		linear_intersection((self.origin,self.through,Extent::Ray),(segment.p1,segment.p2,Extent::Segment))
+	}
+}
+
+impl<T:FloatCoordinate> PartialEq for InfiniteLine2D<T> {
+    fn eq(&self, other: &InfiniteLine2D<T>) -> bool {
+		self.side(&other.p1) == TurnDirection::NoTurn && self.side(&other.p2) == TurnDirection::NoTurn
+    }
+}
+
+impl<T:FloatCoordinate> PartialEq for Ray2D<T> {
+    fn eq(&self, other: &Ray2D<T>) -> bool {
+		self.origin == other.origin && self.side(&other.through) == TurnDirection::NoTurn &&
+			self.direction().dot(&other.direction()) > T::zero()
+    }
+}
+
+impl<T:FloatCoordinate> ApproxEq<T> for Line2D<T> {
+	fn approx_eq(&self, other: &Line2D<T>, tolerance: &Tolerance<T>) -> bool {
+		(self.p1.approx_eq(&other.p1,tolerance) && self.p2.approx_eq(&other.p2,tolerance)) ||
+			(self.p2.approx_eq(&other.p1,tolerance) && self.p1.approx_eq(&other.p2,tolerance))
+	}
+}
+
+impl<T:FloatCoordinate> ApproxEq<T> for InfiniteLine2D<T> {
+	fn approx_eq(&self, other: &InfiniteLine2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.p1.turn_direction_with_tolerance(&self.p2,&other.p1,tolerance) == TurnDirection::NoTurn &&
+			self.p1.turn_direction_with_tolerance(&self.p2,&other.p2,tolerance) == TurnDirection::NoTurn
+	}
+}
+
+impl<T:FloatCoordinate> ApproxEq<T> for Ray2D<T> {
+	fn approx_eq(&self, other: &Ray2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.origin.approx_eq(&other.origin,tolerance) &&
+			self.origin.turn_direction_with_tolerance(&self.through,&other.through,tolerance) == TurnDirection::NoTurn &&
+			self.direction().dot(&other.direction()) > T::zero()
+	}
+}
+
+///Result of intersecting lines, rays and segments with each other.
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinearIntersection<T: FloatCoordinate>{
+	///The objects do not touch.
+	None,
+	///The objects meet in a single point.
+	Point(Point2D<T>),
+	///The objects are collinear and share this segment.
+	Segment(Line2D<T>),
+	///The objects are collinear and share this ray.
+	Ray(Ray2D<T>),
+	///The objects are the same infinite line.
+	Line(InfiniteLine2D<T>),
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for LinearIntersection<T> {
+	fn approx_eq(&self, other: &LinearIntersection<T>, tolerance: &Tolerance<T>) -> bool {
+		match (self,other){
+			(LinearIntersection::None,LinearIntersection::None) => true,
+			(LinearIntersection::Point(a),LinearIntersection::Point(b)) => a.approx_eq(b,tolerance),
+			(LinearIntersection::Segment(a),LinearIntersection::Segment(b)) => a.approx_eq(b,tolerance),
+			(LinearIntersection::Ray(a),LinearIntersection::Ray(b)) => a.approx_eq(b,tolerance),
+			(LinearIntersection::Line(a),LinearIntersection::Line(b)) => a.approx_eq(b,tolerance),
+			_ => false,
+		}
+	}
+}
+
+///How far a linear object extends from p1 (t = 0) through p2 (t = 1).
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+enum Extent{
+	Line,
+	Ray,
+	Segment,
+}
+
+fn turn_sign(turn: TurnDirection) -> i8{
+	match turn{
+		TurnDirection::LeftTurn => 1,
+		TurnDirection::RightTurn => -1,
+		TurnDirection::NoTurn => 0,
+	}
+}
+
+///Returns true if the point where an object's supporting line crosses a non parallel line is within its extent.
+///'side1' and 'side2' are the signs of the object's p1 and p2 relative to the other line and
+///'turn' is the sign of the cross product of the object's direction with the other line's direction.
+fn crossing_within_extent(extent: Extent, side1: i8, side2: i8, turn: i8) -> bool{
+	match extent{
+		Extent::Line => true,
+		Extent::Ray => side1 * turn >= 0,
+		Extent::Segment => side1 * side2 <= 0,
+	}
+}
+
+///Intersects two linear objects, each given as two distinct points and an extent.
+fn linear_intersection<T: FloatCoordinate>(a: (Point2D<T>,Point2D<T>,Extent), b: (Point2D<T>,Point2D<T>,Extent)) -> LinearIntersection<T>{
+	let (a1,a2,a_extent) = a;
+	let (b1,b2,b_extent) = b;
+	let d_a = a2 - a1;
+	let d_b = b2 - b1;
+
+	let a1_side = turn_sign(b1.turn_direction(&b2,&a1));
+	let a2_side = turn_sign(b1.turn_direction(&b2,&a2));
+	let b1_side = turn_sign(a1.turn_direction(&a2,&b1));
+	let b2_side = turn_sign(a1.turn_direction(&a2,&b2));
+
+	let zero = Point2D::new(T::zero(),T::zero());
+	let direction_turn = turn_sign(zero.turn_direction(&Point2D::new(d_a.x,d_a.y),&Point2D::new(d_b.x,d_b.y)));
+
+	if direction_turn == 0 || (b1_side == 0 && b2_side == 0){
+		if b1_side != 0 || b2_side != 0{
+			return LinearIntersection::None;
+		}
+		return collinear_linear_intersection(a,b);
+	}
+
+	if !crossing_within_extent(a_extent,a1_side,a2_side,direction_turn) ||
+		!crossing_within_extent(b_extent,b1_side,b2_side,-direction_turn){
+		return LinearIntersection::None;
+	}
+
+	if a1_side == 0{
+		LinearIntersection::Point(a1)
+	}
+	else if a2_side == 0{
+		LinearIntersection::Point(a2)
+	}
+	else if b1_side == 0{
+		LinearIntersection::Point(b1)
+	}
+	else if b2_side == 0{
+		LinearIntersection::Point(b2)
+	}
+	else{
+		let mut t = (b1 - a1).cross(&d_b) / d_a.cross(&d_b);
+		if a_extent != Extent::Line{
+			t = t.max(T::zero());
+		}
+		if a_extent == Extent::Segment{
+			t = t.min(T::one());
+		}
+		LinearIntersection::Point(a1 + d_a * t)
+	}
+}
+
+///Intersects two collinear linear objects by comparing their extents along the direction of a.
+fn collinear_linear_intersection<T: FloatCoordinate>(a: (Point2D<T>,Point2D<T>,Extent), b: (Point2D<T>,Point2D<T>,Extent)) -> LinearIntersection<T>{
+	let (a1,a2,a_extent) = a;
+	let (b1,b2,b_extent) = b;
+	let d_a = a2 - a1;
+	let key = |p: &Point2D<T>| (*p - a1).dot(&d_a);
+
+	//Each bound is a key and the point it belongs to, None being unbounded.
+	let (mut low,mut high) = match a_extent{
+		Extent::Line => (None,None),
+		Extent::Ray => (Some((T::zero(),a1)),None),
+		Extent::Segment => (Some((T::zero(),a1)),Some((key(&a2),a2))),
+	};
+
+	let (b1_key,b2_key) = (key(&b1),key(&b2));
+	let (b_low,b_high) = match b_extent{
+		Extent::Line => (None,None),
+		Extent::Ray if b1_key < b2_key => (Some((b1_key,b1)),None),
+		Extent::Ray => (None,Some((b1_key,b1))),
+		Extent::Segment if b1_key < b2_key => (Some((b1_key,b1)),Some((b2_key,b2))),
+		Extent::Segment => (Some((b2_key,b2)),Some((b1_key,b1))),
+	};
+
+	if let Some(b_low) = b_low{
+		if low.is_none_or(|low| b_low.0 > low.0){
+			low = Some(b_low);
+		}
+	}
+	if let Some(b_high) = b_high{
+		if high.is_none_or(|high| b_high.0 < high.0){
+			high = Some(b_high);
+		}
+	}
+
+	match (low,high){
+		(Some((low_key,low_point)),Some((high_key,high_point))) =>{
+			if low_key > high_key{
+				LinearIntersection::None
+			}
+			else if low_key == high_key{
+				LinearIntersection::Point(low_point)
+			}
+			else{
+				LinearIntersection::Segment(Line2D::new(low_point,high_point))
+			}
+		},
+		(Some((_,low_point)),None) => LinearIntersection::Ray(Ray2D::from_direction(low_point,d_a)),
+		(None,Some((_,high_point))) => LinearIntersection::Ray(Ray2D::from_direction(high_point,-d_a)),
+		(None,None) => LinearIntersection::Line(InfiniteLine2D::new(a1,a2)),
+	}
+}
+
+///2D Axis aligned rectangle
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect2D<T: Coordinate>{
+	///Corner with the smallest coordinates
+	pub min : Point2D<T>,
+	///Corner with the largest coordinates
+	pub max : Point2D<T>,
+}
+
+impl<T: Coordinate> Rect2D<T >{
+	///Creates the rectangle spanned by two opposite corners.
+	pub fn new(p1:Point2D<T>,p2:Point2D<T>) -> Self{
+		Rect2D{
+			min: Point2D::new(smaller(&p1.x,&p2.x),smaller(&p1.y,&p2.y)),
+			max: Point2D::new(larger(&p1.x,&p2.x),larger(&p1.y,&p2.y)),
+		}
+	}
+
+	///Creates the bounding box of a set of points, or None if there are no points.
+	pub fn from_points(points: &[Point2D<T>]) -> Option<Self>{
+		Rect2D::from_polygon(points.iter())
+	}
+
+	///Creates the bounding box of a line.
+	pub fn from_line(line: &Line2D<T>) -> Self{
+		line.bounding_box()
+	}
+
+	///Creates the bounding box of an iterator of polygon points, or None if there are no points.
+	pub fn from_polygon<I,P>(mut points : I) -> Option<Self> where
+		I : Iterator<Item = P>,
+		P : Deref<Target = Point2D<T>>{
+
+		let first = (*points.next()?).clone();
+		Some(points.fold(Rect2D::new(first.clone(),first),|rect,p| rect.expand_to_point(&p)))
+	}
 
-		let left  = line1.1 * line2.0;
-		let right = line1.0 * line2.1;
-		if left> right
-		{
-			TurnDirection::RightTurn
+	///Returns the smallest rectangle containing this rectangle and 'point'.
+	pub fn expand_to_point(&self, point: &Point2D<T>) -> Rect2D<T>{
+		Rect2D{
+			min: Point2D::new(smaller(&self.min.x,&point.x),smaller(&self.min.y,&point.y)),
+			max: Point2D::new(larger(&self.max.x,&point.x),larger(&self.max.y,&point.y)),
 		}
-		else if left < right
-		{
-			TurnDirection::LeftTurn
+	}
+
+	///Returns true if 'point' is inside or on the border of the rectangle.
+	pub fn contains_point(&self, point: &Point2D<T>) -> bool{
+		point.x >= self.min.x && point.x <= self.max.x &&
+			point.y >= self.min.y && point.y <= self.max.y
+	}
+
+	///Returns true if the rectangles overlap or touch.
+	pub fn intersects_rect(&self, other: &Rect2D<T>) -> bool{
+		self.min.x <= other.max.x && other.min.x <= self.max.x &&
+			self.min.y <= other.max.y && other.min.y <= self.max.y
+	}
+
+	///Returns the smallest rectangle containing both rectangles.
+	pub fn union(&self, other: &Rect2D<T>) -> Rect2D<T>{
+		self.expand_to_point(&other.min).expand_to_point(&other.max)
+	}
+
+	///Returns the overlapping area of the rectangles, or None if they don't intersect.
+	pub fn intersection(&self, other: &Rect2D<T>) -> Option<Rect2D<T>>{
+		if !self.intersects_rect(other){
+			None
 		}
 		else{
-			TurnDirection::NoTurn
+			Some(Rect2D{
+				min: Point2D::new(larger(&self.min.x,&other.min.x),larger(&self.min.y,&other.min.y)),
+				max: Point2D::new(smaller(&self.max.x,&other.max.x),smaller(&self.max.y,&other.max.y)),
+			})
 		}
 	}
+}
 
-	///Partial comparision function ordered by x value then by y in case of ties.
-	pub fn x_then_y_partial_cmp(&self,other: &Point2D<T>) -> Option<Ordering> {
-		if self.x != other.x{
-			self.x.partial_cmp(&other.x)
+//Returns the smaller value, a NaN is ignored like in Float::min.
+fn smaller<T: Coordinate>(a: &T, b: &T) -> T{
+	let a_is_nan = a.partial_cmp(a).is_none();
+	if a_is_nan || b < a {b.clone()} else {a.clone()}
+}
+
+//Returns the larger value, a NaN is ignored like in Float::max.
+fn larger<T: Coordinate>(a: &T, b: &T) -> T{
+	let a_is_nan = a.partial_cmp(a).is_none();
+	if a_is_nan || b > a {b.clone()} else {a.clone()}
+}
+
+impl<T: FloatCoordinate> Rect2D<T >{
+	///Returns the width of the rectangle.
+	pub fn width(&self) -> T{
+		self.max.x - self.min.x
+	}
+
+	///Returns the height of the rectangle.
+	pub fn height(&self) -> T{
+		self.max.y - self.min.y
+	}
+
+	///Returns the area of the rectangle.
+	pub fn area(&self) -> T{
+		self.width() * self.height()
+	}
+
+	///Returns the center of the rectangle.
+	pub fn center(&self) -> Point2D<T>{
+		let two = T::one() + T::one();
+		Point2D::new((self.min.x + self.max.x) / two,(self.min.y + self.max.y) / two)
+	}
+
+	///Returns the rectangle grown by 'margin' on every side. A negative margin shrinks it,
+	///a side shrunk by more than half its length collapses onto the center of the rectangle.
+	pub fn expand_by(&self, margin: T) -> Rect2D<T>{
+		let center = self.center();
+		let (min_x,max_x) = expand_interval(self.min.x,self.max.x,center.x,margin);
+		let (min_y,max_y) = expand_interval(self.min.y,self.max.y,center.y,margin);
+		Rect2D{min: Point2D::new(min_x,min_y),max: Point2D::new(max_x,max_y)}
+	}
+}
+
+//Grows the interval min..max by margin at both ends, an inverted result collapses to center.
+fn expand_interval<T: FloatCoordinate>(min: T, max: T, center: T, margin: T) -> (T,T){
+	let (min,max) = (min - margin,max + margin);
+	if min > max{
+		(center,center)
+	}
+	else{
+		(min,max)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Rect2D<T> {
+	fn approx_eq(&self, other: &Rect2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.min.approx_eq(&other.min,tolerance) && self.max.approx_eq(&other.max,tolerance)
+	}
+}
+
+///2D Circle
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle2D<T: FloatCoordinate>{
+	///Center of the circle
+	pub center : Point2D<T>,
+	///Radius of the circle
+	pub radius : T,
+}
+
+impl<T: FloatCoordinate> Circle2D<T >{
+	///Creates a circle from its center and radius.
+	pub fn new(center:Point2D<T>,radius:T) -> Self{
+		Circle2D{center,radius}
+	}
+
+	///Creates the circumcircle of three points, or None if they are collinear.
+	pub fn from_three_points(p1:&Point2D<T>,p2:&Point2D<T>,p3:&Point2D<T>) -> Option<Self>{
+		if p1.turn_direction(p2,p3) == TurnDirection::NoTurn{
+			return None;
+		}
+
+		let b = *p2 - *p1;
+		let c = *p3 - *p1;
+		let d = (b.cross(&c)) * (T::one() + T::one());
+		let b_len = b.norm_squared();
+		let c_len = c.norm_squared();
+		let offset = Vector2D::new((c.y * b_len - b.y * c_len) / d,(b.x * c_len - c.x * b_len) / d);
+
+		Some(Circle2D{center: *p1 + offset,radius: offset.norm()})
+	}
+
+	///Returns the area of the circle.
+	pub fn area(&self) -> T{
+		T::from(std::f64::consts::PI).unwrap() * self.radius * self.radius
+	}
+
+	///Returns true if 'point' is inside or on the circle.
+	pub fn contains_point(&self, point: &Point2D<T>) -> bool{
+		self.center.distance_squared(point) <= self.radius * self.radius
+	}
+
+	///Returns the intersection points with an infinite line.
+	pub fn intersection_with_line(&self, line: &InfiniteLine2D<T>) -> Vec<Point2D<T>>{
+		self.intersection_parameters(&line.p1,&line.direction()).into_iter()
+			.map(|t| line.p1 + line.direction() * t)
+			.collect()
+	}
+
+	///Returns the intersection points with a ray.
+	pub fn intersection_with_ray(&self, ray: &Ray2D<T>) -> Vec<Point2D<T>>{
+		self.intersection_parameters(&ray.origin,&ray.direction()).into_iter()
+			.filter(|&t| t >= T::zero())
+			.map(|t| ray.origin + ray.direction() * t)
+			.collect()
+	}
+
+	///Returns the intersection points with a line segment.
+	pub fn intersection_with_segment(&self, segment: &Line2D<T>) -> Vec<Point2D<T>>{
+		self.intersection_parameters(&segment.p1,&segment.direction()).into_iter()
+			.filter(|&t| t >= T::zero() && t <= T::one())
+			.map(|t| segment.p1 + segment.direction() * t)
+			.collect()
+	}
+
+	///Returns the intersection points with another circle.
+	///Concentric circles, including identical ones, return no points.
+	pub fn intersection_with_circle(&self, other: &Circle2D<T>) -> Vec<Point2D<T>>{
+		let offset = other.center - self.center;
+		let distance = offset.norm();
+
+		if distance.is_zero() ||
+			distance > self.radius + other.radius ||
+			distance < (self.radius - other.radius).abs(){
+			return Vec::new();
+		}
+
+		let two = T::one() + T::one();
+		let along = (self.radius * self.radius - other.radius * other.radius + distance * distance) / (two * distance);
+		let height_squared = self.radius * self.radius - along * along;
+		let middle = self.center + offset * (along / distance);
+
+		if height_squared <= T::zero(){
+			vec![middle]
 		}
 		else{
-			self.y.partial_cmp(&other.y)
+			let across = offset.perp() * (height_squared.sqrt() / distance);
+			vec![middle + across,middle - across]
 		}
 	}
 
-	///Comparision function ordered by x value then by y in case of ties.
-	pub fn x_then_y_cmp(&self,other: &Point2D<T>) -> Ordering {
-		if let Some(ord)= self.x_then_y_partial_cmp(other) {
-			ord
+	///Returns the parameters t where origin + direction * t is on the circle, in increasing order.
+	fn intersection_parameters(&self, origin: &Point2D<T>,direction: &Vector2D<T>) -> Vec<T>{
+		let two = T::one() + T::one();
+		let offset = *origin - self.center;
+		let a = direction.norm_squared();
+		let b = offset.dot(direction) * two;
+		let c = offset.norm_squared() - self.radius * self.radius;
+		let discriminant = b * b - a * c * two * two;
+
+		if a.is_zero() || discriminant < T::zero(){
+			Vec::new()
+		}
+		else if discriminant.is_zero(){
+			vec![-b / (two * a)]
 		}
 		else{
-			Ordering::Greater
+			let root = discriminant.sqrt();
+			vec![(-b - root) / (two * a),(-b + root) / (two * a)]
 		}
 	}
+}
 
-	///Partial comparision function ordered polar cordinate in relation to rotational point.
-	pub fn rotation_point_cmp(&self,other: &Point2D<T>,rotational_point: &Point2D<T>) -> Option<Ordering> {
-		match rotational_point.turn_direction(self,other){
-			TurnDirection::LeftTurn => Some(Ordering::Greater),
-			TurnDirection::RightTurn  => Some(Ordering::Less),
-			TurnDirection::NoTurn    => {
-				self.x_then_y_partial_cmp(other)
-			},
-		}
+impl<T: FloatCoordinate> ApproxEq<T> for Circle2D<T> {
+	fn approx_eq(&self, other: &Circle2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.center.approx_eq(&other.center,tolerance) && tolerance.eq(self.radius,other.radius)
 	}
 }
 
-///2D Line
-#[derive(Copy,Clone,Debug)]
-pub struct Line2D<T: Float+Zero>{
+///2D Triangle
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle2D<T: FloatCoordinate>{
 	///Point 1
 	pub p1 : Point2D<T>,
 	///Point 2
 	pub p2 : Point2D<T>,
+	///Point 3
+	pub p3 : Point2D<T>,
 }
 
-impl<T: Float+Zero> Line2D<T >{
-	///Creates a 2D Line from p1 to p2.
-	pub fn new(p1:Point2D<T>,p2:Point2D<T>) -> Self{
-		Line2D{p1,p2}
+impl<T: FloatCoordinate> Triangle2D<T >{
+	///Creates a triangle from three points.
+	pub fn new(p1:Point2D<T>,p2:Point2D<T>,p3:Point2D<T>) -> Self{
+		Triangle2D{p1,p2,p3}
 	}
 
-	///returns true if 'point' is on this line
-	pub fn contains_point(&self, point: &Point2D<T>) -> bool{
+	///Returns the points of the triangle.
+	pub fn points(&self) -> [Point2D<T>;3]{
+		[self.p1,self.p2,self.p3]
+	}
 
-		if point.x < self.p1.x.min(self.p2.x) ||
-			(point.y < self.p1.x.min(self.p2.y)) ||
-			(point.x > self.p1.x.max(self.p2.x)) ||
-			(point.y > self.p1.y.max(self.p2.y)){
+	///Returns the edges p1 to p2, p2 to p3 and p3 to p1.
+	pub fn edges(&self) -> [Line2D<T>;3]{
+		[Line2D::new(self.p1,self.p2),Line2D::new(self.p2,self.p3),Line2D::new(self.p3,self.p1)]
+	}
 
-				return false;
-		}
+	///Returns the area, positive for counter-clockwise triangles and negative for clockwise ones.
+	///Same sign convention as polygon_area::signed_shoelace.
+	pub fn signed_area(&self) -> T{
+		(self.p2 - self.p1).cross(&(self.p3 - self.p1)) / (T::one() + T::one())
+	}
 
-		let range_x = self.p2.x - self.p1.x;
-		let range_y = self.p2.y - self.p1.y;
+	///Returns the area of the triangle.
+	pub fn area(&self) -> T{
+		self.signed_area().abs()
+	}
 
-		let x = (point.x - self.p1.x) / range_x;
-		let y = (point.y - self.p1.y) / range_y;
+	///Returns the rotational direction of p1, p2 and p3.
+	pub fn orientation(&self) -> TurnDirection{
+		self.p1.turn_direction(&self.p2,&self.p3)
+	}
 
-		x == y
+	///Returns the barycentric coordinates of 'point' relative to p1, p2 and p3, or None for a degenerate triangle.
+	pub fn barycentric(&self, point: &Point2D<T>) -> Option<[T;3]>{
+		let total = (self.p2 - self.p1).cross(&(self.p3 - self.p1));
+		if total.is_zero(){
+			return None;
+		}
+		let l1 = (self.p2 - *point).cross(&(self.p3 - *point)) / total;
+		let l2 = (self.p3 - *point).cross(&(self.p1 - *point)) / total;
+		Some([l1,l2,T::one() - l1 - l2])
 	}
 
-	///Returns true if the lines intersect.
-	pub fn intersects_with_line(&self, other: &Line2D<T>) -> bool{
+	///Returns true if 'point' is inside or on the border of the triangle.
+	pub fn contains_point(&self, point: &Point2D<T>) -> bool{
+		let orientation = self.orientation();
+		if orientation == TurnDirection::NoTurn{
+			return self.edges().iter().any(|edge| edge.contains_point(point));
+		}
+		self.edges().iter().all(|edge|{
+			let turn = edge.p1.turn_direction(&edge.p2,point);
+			turn == orientation || turn == TurnDirection::NoTurn
+		})
+	}
 
-		((self.p1.turn_direction(&self.p2,&other.p1) !=  self.p1.turn_direction(&self.p2,&other.p2)) &&
-		(other.p1.turn_direction(&other.p2,&self.p1) != other.p1.turn_direction(&other.p2,&self.p2))) ||
-		((self.p1.turn_direction(&self.p2,&other.p1) == TurnDirection::NoTurn) && (self.p1.turn_direction(&self.p2,&other.p2) == TurnDirection::NoTurn)&&
-		(self.contains_point(&other.p1) ||self.contains_point(&other.p2)))
+	///Returns true if 'point' is strictly inside the triangle.
+	pub fn contains_point_strict(&self, point: &Point2D<T>) -> bool{
+		let orientation = self.orientation();
+		orientation != TurnDirection::NoTurn &&
+			self.edges().iter().all(|edge| edge.p1.turn_direction(&edge.p2,point) == orientation)
+	}
 
+	///Returns the centroid of the triangle.
+	pub fn centroid(&self) -> Point2D<T>{
+		let three = T::from(3).unwrap();
+		Point2D::new((self.p1.x + self.p2.x + self.p3.x) / three,(self.p1.y + self.p2.y + self.p3.y) / three)
 	}
 
-	///Returns the intersection point between 2 lines, or None if they don't intersect.
-	pub fn intersection_point(&self, other: &Line2D<T>) -> Option<Point2D<T>>{
+	///Returns the center of the circumcircle, or None for a degenerate triangle.
+	pub fn circumcenter(&self) -> Option<Point2D<T>>{
+		Circle2D::from_three_points(&self.p1,&self.p2,&self.p3).map(|circle| circle.center)
+	}
 
-		if !self.intersects_with_line(other){
-			None
+	///Returns the center of the inscribed circle.
+	pub fn incenter(&self) -> Point2D<T>{
+		let a = self.p2.distance(&self.p3);
+		let b = self.p3.distance(&self.p1);
+		let c = self.p1.distance(&self.p2);
+		let perimeter = a + b + c;
+		if perimeter.is_zero(){
+			return self.p1;
 		}
-		else{
-			let slope_self = (self.p2.y - self.p1.y)/(self.p2.x - self.p1.x);
-			let slope_other = (other.p2.y - other.p1.y)/(other.p2.x - other.p1.x);
-
-
-			if slope_other == slope_self{
-				if self.p1.y < other.p1.y.max(other.p2.y) && self.p1.y > other.p1.y.min(other.p2.y) {
-					Some(self.p1)
-				}
-				else{
-					Some(self.p2)
-				}
-			}
-			else{
-				let x = (other.p1.y - self.p1.y - (slope_other*other.p1.x) + (slope_self*self.p1.x))/ (slope_self-slope_other);
-				let y = slope_self*(x-self.p1.x) + self.p1.y;
+		Point2D::new(
+			(self.p1.x * a + self.p2.x * b + self.p3.x * c) / perimeter,
+			(self.p1.y * a + self.p2.y * b + self.p3.y * c) / perimeter)
+	}
 
-				Some(Point2D::new(x,y))
+	///Returns the longest edge of the triangle.
+	pub fn longest_edge(&self) -> Line2D<T>{
+		let edges = self.edges();
+		let mut longest = edges[0];
+		for edge in edges.iter().skip(1){
+			if edge.length() > longest.length(){
+				longest = *edge;
 			}
 		}
-
+		longest
 	}
 
+	///Returns the smallest interior angle in radians, zero for a degenerate triangle.
+	pub fn min_angle(&self) -> T{
+		let angle = |corner: Point2D<T>,a: Point2D<T>,b: Point2D<T>|{
+			let u = a - corner;
+			let v = b - corner;
+			u.cross(&v).abs().atan2(u.dot(&v))
+		};
+		angle(self.p1,self.p2,self.p3)
+			.min(angle(self.p2,self.p3,self.p1))
+			.min(angle(self.p3,self.p1,self.p2))
+	}
 }
 
-
-impl<T:Float+Zero> Ord for Line2D<T> {
-    fn cmp(&self, other: &Line2D<T>) -> Ordering {
-       match self.p1.x_then_y_cmp(&other.p1){
-		   Ordering::Greater => Ordering::Greater,
-		   Ordering::Less => Ordering::Less,
-		   Ordering::Equal => self.p2.x_then_y_cmp(&other.p2),
-	   }
-    }
+impl<T: FloatCoordinate> ApproxEq<T> for Triangle2D<T> {
+	fn approx_eq(&self, other: &Triangle2D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.p1.approx_eq(&other.p1,tolerance) && self.p2.approx_eq(&other.p2,tolerance) && self.p3.approx_eq(&other.p3,tolerance)
+	}
 }
 
-impl<T:Float+Zero> PartialOrd for Line2D<T> {
-    fn partial_cmp(&self, other: &Line2D<T>) -> Option<Ordering> {
-		match self.p1.x_then_y_partial_cmp(&other.p1)?{
-		   Ordering::Greater => Some(Ordering::Greater),
-		   Ordering::Less => Some(Ordering::Less),
-		   Ordering::Equal => self.p2.x_then_y_partial_cmp(&other.p2),
-	   }
-    }
+///Result of intersecting two line segments.
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SegmentIntersection<T: FloatCoordinate>{
+	///The segments do not touch.
+	None,
+	///The segments meet in a single point.
+	Point(Point2D<T>),
+	///The segments are collinear and share this segment.
+	Overlap(Line2D<T>),
 }
 
-impl<T:Float+Zero> PartialEq for Line2D<T> {
-    fn eq(&self, other: &Line2D<T>) -> bool {
-		(self.p1 == other.p1 && self.p2 == other.p2)|| (self.p2 == other.p1 && self.p1 == other.p2)
-    }
-}
-impl<T:Float+Zero> Eq for Line2D<T> {
+impl<T: FloatCoordinate> ApproxEq<T> for SegmentIntersection<T> {
+	fn approx_eq(&self, other: &SegmentIntersection<T>, tolerance: &Tolerance<T>) -> bool {
+		match (self,other){
+			(SegmentIntersection::None,SegmentIntersection::None) => true,
+			(SegmentIntersection::Point(a),SegmentIntersection::Point(b)) => a.approx_eq(b,tolerance),
+			(SegmentIntersection::Overlap(a),SegmentIntersection::Overlap(b)) => a.approx_eq(b,tolerance),
+			_ => false,
+		}
+	}
 }
 
+///Enum representing the position of a point relative to a circle.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CirclePosition{
+	///Strictly inside the circle.
+	Inside,
+	///Strictly outside the circle.
+	Outside,
+	///On the circle.
+	OnCircle,
+}
 
 ///Enum representing rotation.
 #[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::enum_variant_names)]
 pub enum TurnDirection{
+	///Clockwise turn.
 	RightTurn,
+	///Counter-clockwise turn.
 	LeftTurn,
+	///The points are collinear.
 	NoTurn,
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod primatives_test {
 	use super::*;
 	
@@ -195,6 +1417,40 @@ mod primatives_test {
 		assert_eq!(p.y, 6.7);
 	}    
 	
+	#[test]
+    fn vector_test() {
+		let v = Vector2D::new(3.0,4.0);
+		let w = Vector2D::new(1.0,-2.0);
+		assert_eq!(v + w, Vector2D::new(4.0,2.0));
+		assert_eq!(v - w, Vector2D::new(2.0,6.0));
+		assert_eq!(v * 2.0, Vector2D::new(6.0,8.0));
+		assert_eq!(-v, Vector2D::new(-3.0,-4.0));
+		assert_eq!(v.dot(&w), -5.0);
+		assert_eq!(v.cross(&w), -10.0);
+		assert_eq!(v.norm(), 5.0);
+		assert_eq!(v.norm_squared(), 25.0);
+		assert_eq!(v.normalize(), Some(Vector2D::new(0.6,0.8)));
+		assert_eq!(Vector2D::<f64>::zero().normalize(), None);
+		assert_eq!(v.perp(), Vector2D::new(-4.0,3.0));
+		assert!(v.cross(&v.perp()) > 0.0);
+
+		let v = Point2D::new(4i64,5) - Point2D::new(1,1);
+		assert_eq!(v, Vector2D::new(3,4));
+		assert_eq!(v.dot(&Vector2D::new(1,-2)), -5);
+		assert_eq!(v.cross(&v.perp()), 25);
+		assert_eq!(Point2D::new(1,1) + v * 2 - -v, Point2D::new(10,13));
+		assert_eq!(Vector2D::<i64>::zero().norm_squared(), 0);
+	}
+
+	#[test]
+    fn point_vector_arithmetic_test() {
+		let p = Point2D::new(1.0,2.0);
+		let q = Point2D::new(4.0,6.0);
+		assert_eq!(q - p, Vector2D::new(3.0,4.0));
+		assert_eq!(p + (q - p), q);
+		assert_eq!(q - (q - p), p);
+	}
+
 	#[test]
     fn turn_direction_test() {
         let p =Point2D::new(0.0,0.0);
@@ -203,6 +1459,33 @@ mod primatives_test {
 		assert_eq!(p.turn_direction(&Point2D::new(1.0,1.0),&Point2D::new(2.0,2.0)), TurnDirection::NoTurn);
 
 	}	
+
+	#[test]
+    fn turn_direction_near_collinear_test() {
+		let q = Point2D::new(12.0,12.0);
+		let r = Point2D::new(24.0,24.0);
+		let step = 2.0f64.powi(-53);
+		for i in 0..32{
+			for j in 0..32{
+				let p = Point2D::new(0.5 + (i as f64)*step,0.5 + (j as f64)*step);
+				let expected = if j > i {TurnDirection::LeftTurn} else if j < i {TurnDirection::RightTurn} else {TurnDirection::NoTurn};
+				assert_eq!(q.turn_direction(&r,&p), expected);
+				assert_eq!(r.turn_direction(&p,&q), expected);
+				assert_eq!(p.turn_direction(&q,&r), expected);
+			}
+		}
+	}
+
+	#[test]
+    fn line_contains_point_near_collinear_test() {
+        let line = Line2D::new(Point2D::new(0.0,0.0),Point2D::new(24.0,24.0));
+		let step = 2.0f64.powi(-53);
+		assert!(line.contains_point(&Point2D::new(0.5,0.5)));
+		assert!(!line.contains_point(&Point2D::new(0.5,0.5 + step)));
+		assert!(!line.contains_point(&Point2D::new(0.5 + step,0.5)));
+		let vertical = Line2D::new(Point2D::new(1.0,0.0),Point2D::new(1.0,2.0));
+		assert!(vertical.contains_point(&Point2D::new(1.0,1.0)));
+	}
 	
 	#[test]
     fn line_intersection_test() {
@@ -225,11 +1508,301 @@ mod primatives_test {
 		assert_eq!(line3.intersection_point(&line2),None );
 		assert_eq!(line2.intersection_point(&line3),None );
 		assert_eq!(line1.intersection_point(&line4),Some(Point2D::new(1.0,1.0)) );
+		assert_eq!(line4.intersection_point(&line1),Some(Point2D::new(0.5,0.5)) );
 		assert_eq!(line1.intersection_point(&line5),None );
 
 
 	}	
 	
+	#[test]
+    fn segment_intersection_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let l = |x1,y1,x2,y2| Line2D::new(Point2D::new(x1,y1),Point2D::new(x2,y2));
+		use SegmentIntersection::{None,Point,Overlap};
+
+		let cases = vec!(
+			//crossing diagonals
+			(l(0.0,0.0,2.0,2.0), l(2.0,0.0,0.0,2.0), Point(p(1.0,1.0))),
+			//vertical and horizontal
+			(l(1.0,0.0,1.0,2.0), l(0.0,1.0,2.0,1.0), Point(p(1.0,1.0))),
+			//vertical and diagonal
+			(l(1.0,-5.0,1.0,5.0), l(0.0,0.0,4.0,2.0), Point(p(1.0,0.5))),
+			//parallel verticals
+			(l(1.0,0.0,1.0,2.0), l(2.0,0.0,2.0,2.0), None),
+			//parallel horizontals
+			(l(0.0,1.0,2.0,1.0), l(0.0,2.0,2.0,2.0), None),
+			//T junction at an endpoint
+			(l(0.0,0.0,2.0,0.0), l(1.0,0.0,1.0,3.0), Point(p(1.0,0.0))),
+			//shared endpoint
+			(l(0.0,0.0,1.0,1.0), l(1.0,1.0,2.0,0.0), Point(p(1.0,1.0))),
+			//lines would cross outside the segments
+			(l(0.0,0.0,1.0,1.0), l(3.0,0.0,2.0,1.0), None),
+			//collinear overlapping
+			(l(0.0,0.0,2.0,2.0), l(1.0,1.0,3.0,3.0), Overlap(l(1.0,1.0,2.0,2.0))),
+			//collinear overlapping reversed
+			(l(0.0,0.0,2.0,2.0), l(3.0,3.0,1.0,1.0), Overlap(l(1.0,1.0,2.0,2.0))),
+			//collinear horizontal contained
+			(l(0.0,0.0,4.0,0.0), l(1.0,0.0,2.0,0.0), Overlap(l(1.0,0.0,2.0,0.0))),
+			//collinear vertical contained
+			(l(0.0,0.0,0.0,4.0), l(0.0,1.0,0.0,2.0), Overlap(l(0.0,1.0,0.0,2.0))),
+			//collinear horizontal touching at one point
+			(l(0.0,0.0,1.0,0.0), l(1.0,0.0,2.0,0.0), Point(p(1.0,0.0))),
+			//collinear disjoint
+			(l(0.0,0.0,1.0,0.0), l(2.0,0.0,3.0,0.0), None),
+			//identical
+			(l(0.0,0.0,1.0,1.0), l(1.0,1.0,0.0,0.0), Overlap(l(0.0,0.0,1.0,1.0))),
+			//degenerate on segment
+			(l(1.0,1.0,1.0,1.0), l(0.0,0.0,2.0,2.0), Point(p(1.0,1.0))),
+			//degenerate off segment
+			(l(1.0,2.0,1.0,2.0), l(0.0,0.0,2.0,2.0), None),
+			//degenerate beyond collinear segment
+			(l(3.0,3.0,3.0,3.0), l(0.0,0.0,2.0,2.0), None),
+			//both degenerate and equal
+			(l(1.0,1.0,1.0,1.0), l(1.0,1.0,1.0,1.0), Point(p(1.0,1.0))),
+			//both degenerate and different
+			(l(1.0,1.0,1.0,1.0), l(1.0,2.0,1.0,2.0), None),
+		);
+
+		for (a,b,expected) in cases{
+			assert_eq!(a.intersection(&b), expected, "{:?} with {:?}", a, b);
+			assert_eq!(b.intersection(&a), expected, "{:?} with {:?}", b, a);
+			assert_eq!(a.intersects_with_line(&b), expected != None, "{:?} with {:?}", a, b);
+			assert_eq!(b.intersects_with_line(&a), expected != None, "{:?} with {:?}", b, a);
+			for (first,second) in [(a,b),(b,a)].iter(){
+				let point = first.intersection_point(second);
+				match expected{
+					None => assert_eq!(point, Option::None, "{:?} with {:?}", first, second),
+					Point(expected) => assert_eq!(point, Some(expected), "{:?} with {:?}", first, second),
+					Overlap(shared) => assert!(point.is_some_and(|point| shared.contains_point(&point)), "{:?} with {:?}", first, second),
+				}
+			}
+		}
+	}
+
+	#[test]
+    fn infinite_line_and_ray_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let line = InfiniteLine2D::new(p(0.0,0.0),p(1.0,1.0));
+		let same_line = InfiniteLine2D::from_direction(p(2.0,2.0),Vector2D::new(-1.0,-1.0));
+		assert_eq!(line, same_line);
+		assert_ne!(line, InfiniteLine2D::new(p(0.0,1.0),p(1.0,2.0)));
+		let ray = Ray2D::new(p(0.0,2.0),p(1.0,2.0));
+		assert_eq!(ray, Ray2D::from_direction(p(0.0,2.0),Vector2D::new(5.0,0.0)));
+		assert_ne!(ray, Ray2D::from_direction(p(0.0,2.0),Vector2D::new(-5.0,0.0)));
+
+		assert_eq!(line.side(&p(0.0,5.0)), TurnDirection::LeftTurn);
+		assert_eq!(line.side(&p(5.0,0.0)), TurnDirection::RightTurn);
+		assert_eq!(line.side(&p(-3.0,-3.0)), TurnDirection::NoTurn);
+		assert_eq!(ray.side(&p(-3.0,2.0)), TurnDirection::NoTurn);
+		assert_eq!(ray.side(&p(-3.0,1.0)), TurnDirection::RightTurn);
+
+		assert_eq!(line.project_point(&p(2.0,0.0)), p(1.0,1.0));
+		assert_eq!(ray.project_point(&p(3.0,5.0)), p(3.0,2.0));
+		assert_eq!(ray.project_point(&p(-3.0,5.0)), p(0.0,2.0));
+	}
+
+	#[test]
+    fn linear_intersection_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let line = |x1,y1,x2,y2| InfiniteLine2D::new(p(x1,y1),p(x2,y2));
+		let ray = |x1,y1,x2,y2| Ray2D::new(p(x1,y1),p(x2,y2));
+		let seg = |x1,y1,x2,y2| Line2D::new(p(x1,y1),p(x2,y2));
+		use LinearIntersection::{None,Point,Segment,Ray,Line};
+
+		//line with line
+		assert_eq!(line(0.0,0.0,1.0,1.0).intersection_with_line(&line(0.0,4.0,1.0,3.0)), Point(p(2.0,2.0)));
+		assert_eq!(line(0.0,0.0,1.0,1.0).intersection_with_line(&line(0.0,1.0,1.0,2.0)), None);
+		assert_eq!(line(0.0,0.0,1.0,1.0).intersection_with_line(&line(3.0,3.0,2.0,2.0)), Line(line(0.0,0.0,1.0,1.0)));
+		assert_eq!(line(1.0,0.0,1.0,1.0).intersection_with_line(&line(0.0,5.0,1.0,5.0)), Point(p(1.0,5.0)));
+
+		//line with ray
+		assert_eq!(line(0.0,0.0,0.0,1.0).intersection_with_ray(&ray(-2.0,3.0,-1.0,3.0)), Point(p(0.0,3.0)));
+		assert_eq!(line(0.0,0.0,0.0,1.0).intersection_with_ray(&ray(-2.0,3.0,-3.0,3.0)), None);
+		assert_eq!(line(0.0,0.0,0.0,1.0).intersection_with_ray(&ray(0.0,3.0,1.0,3.0)), Point(p(0.0,3.0)));
+		assert_eq!(line(0.0,0.0,0.0,1.0).intersection_with_ray(&ray(0.0,3.0,0.0,1.0)), Ray(ray(0.0,3.0,0.0,1.0)));
+		assert_eq!(ray(-2.0,3.0,-1.0,3.0).intersection_with_line(&line(0.0,0.0,0.0,1.0)), Point(p(0.0,3.0)));
+
+		//line with segment
+		assert_eq!(line(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(3.0,-1.0,3.0,1.0)), Point(p(3.0,0.0)));
+		assert_eq!(line(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(3.0,1.0,3.0,2.0)), None);
+		assert_eq!(line(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(3.0,0.0,3.0,2.0)), Point(p(3.0,0.0)));
+		assert_eq!(line(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(3.0,0.0,-3.0,0.0)), Segment(seg(-3.0,0.0,3.0,0.0)));
+
+		//ray with ray
+		assert_eq!(ray(0.0,0.0,1.0,1.0).intersection_with_ray(&ray(4.0,0.0,3.0,1.0)), Point(p(2.0,2.0)));
+		assert_eq!(ray(0.0,0.0,1.0,1.0).intersection_with_ray(&ray(4.0,0.0,5.0,-1.0)), None);
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_ray(&ray(2.0,0.0,3.0,0.0)), Ray(ray(2.0,0.0,3.0,0.0)));
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_ray(&ray(2.0,0.0,1.0,0.0)), Segment(seg(0.0,0.0,2.0,0.0)));
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_ray(&ray(0.0,0.0,-1.0,0.0)), Point(p(0.0,0.0)));
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_ray(&ray(-1.0,0.0,-2.0,0.0)), None);
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_ray(&ray(0.0,1.0,1.0,1.0)), None);
+
+		//ray with segment
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(5.0,-1.0,5.0,1.0)), Point(p(5.0,0.0)));
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(-5.0,-1.0,-5.0,1.0)), None);
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(-5.0,0.0,5.0,0.0)), Segment(seg(0.0,0.0,5.0,0.0)));
+		assert_eq!(ray(0.0,0.0,1.0,0.0).intersection_with_segment(&seg(-5.0,0.0,-1.0,0.0)), None);
+		assert_eq!(ray(0.0,0.0,1.0,1.0).intersection_with_segment(&seg(0.0,4.0,4.0,0.0)), Point(p(2.0,2.0)));
+	}
+
+	#[test]
+    fn distance_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let l = |x1,y1,x2,y2| Line2D::new(Point2D::new(x1,y1),Point2D::new(x2,y2));
+
+		assert_eq!(p(1.0,1.0).distance(&p(4.0,5.0)), 5.0);
+		assert_eq!(p(1.0,1.0).distance_squared(&p(4.0,5.0)), 25.0);
+		assert_eq!(l(0.0,0.0,3.0,4.0).length(), 5.0);
+
+		let line = l(0.0,0.0,4.0,0.0);
+		assert_eq!(line.projection_parameter(&p(1.0,3.0)), 0.25);
+		assert_eq!(line.projection_parameter(&p(-4.0,3.0)), -1.0);
+		assert_eq!(line.closest_point(&p(1.0,3.0)), p(1.0,0.0));
+		assert_eq!(line.closest_point(&p(-4.0,3.0)), p(0.0,0.0));
+		assert_eq!(line.closest_point(&p(7.0,-1.0)), p(4.0,0.0));
+		assert_eq!(line.distance_to_point(&p(1.0,3.0)), 3.0);
+		assert_eq!(line.distance_to_point(&p(7.0,4.0)), 5.0);
+		assert_eq!(l(1.0,1.0,1.0,1.0).distance_to_point(&p(4.0,5.0)), 5.0);
+
+		assert_eq!(line.distance_to_segment(&l(2.0,-1.0,2.0,1.0)), 0.0);
+		assert_eq!(line.distance_to_segment(&l(2.0,0.0,2.0,1.0)), 0.0);
+		assert_eq!(line.distance_to_segment(&l(2.0,2.0,2.0,1.0)), 1.0);
+		assert_eq!(line.distance_to_segment(&l(0.0,2.0,4.0,2.0)), 2.0);
+		assert_eq!(line.distance_to_segment(&l(7.0,4.0,10.0,8.0)), 5.0);
+		assert_eq!(l(7.0,4.0,10.0,8.0).distance_to_segment(&line), 5.0);
+	}
+
+	#[test]
+    fn rect_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let rect = Rect2D::from_points(&[p(1.0,4.0),p(3.0,0.0),p(0.0,2.0)]).unwrap();
+		assert_eq!(rect, Rect2D::new(p(3.0,4.0),p(0.0,0.0)));
+		assert_eq!(rect.min, p(0.0,0.0));
+		assert_eq!(rect.max, p(3.0,4.0));
+		assert_eq!(Rect2D::<f64>::from_points(&[]), None);
+		assert_eq!(Rect2D::from_line(&Line2D::new(p(2.0,-1.0),p(-2.0,1.0))), Rect2D::new(p(-2.0,-1.0),p(2.0,1.0)));
+		assert_eq!(Rect2D::from_polygon(vec!(p(1.0,1.0),p(2.0,5.0)).iter()), Some(Rect2D::new(p(1.0,1.0),p(2.0,5.0))));
+
+		assert_eq!(rect.area(), 12.0);
+		assert_eq!(rect.center(), p(1.5,2.0));
+		assert!(rect.contains_point(&p(3.0,4.0)));
+		assert!(rect.contains_point(&p(1.0,1.0)));
+		assert!(!rect.contains_point(&p(3.5,1.0)));
+
+		let other = Rect2D::new(p(2.0,3.0),p(5.0,6.0));
+		let far = Rect2D::new(p(10.0,10.0),p(11.0,11.0));
+		assert!(rect.intersects_rect(&other));
+		assert!(!rect.intersects_rect(&far));
+		assert!(rect.intersects_rect(&Rect2D::new(p(3.0,4.0),p(5.0,5.0))));
+		assert_eq!(rect.union(&other), Rect2D::new(p(0.0,0.0),p(5.0,6.0)));
+		assert_eq!(rect.intersection(&other), Some(Rect2D::new(p(2.0,3.0),p(3.0,4.0))));
+		assert_eq!(rect.intersection(&far), None);
+		assert_eq!(rect.expand_by(1.0), Rect2D::new(p(-1.0,-1.0),p(4.0,5.0)));
+		assert_eq!(rect.expand_by(-1.0), Rect2D::new(p(1.0,1.0),p(2.0,3.0)));
+		assert_eq!(rect.expand_by(-1.75), Rect2D::new(p(1.5,1.75),p(1.5,2.25)));
+		assert_eq!(rect.expand_by(-5.0), Rect2D::new(p(1.5,2.0),p(1.5,2.0)));
+
+		let rect = Rect2D::new(Point2D::new(i64::MAX,0),Point2D::new(i64::MIN,-5));
+		assert_eq!(rect.min, Point2D::new(i64::MIN,-5));
+		assert!(rect.contains_point(&Point2D::new(0,-5)));
+		assert!(!rect.intersects_rect(&Rect2D::new(Point2D::new(0,1),Point2D::new(1,2))));
+		assert_eq!(Rect2D::new(p(f64::NAN,1.0),p(2.0,f64::NAN)), Rect2D::new(p(2.0,1.0),p(2.0,1.0)));
+	}
+
+	#[test]
+    fn circle_position_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let (a,b,c) = (p(0.0,0.0),p(1.0,0.0),p(0.0,1.0));
+		assert_eq!(p(0.5,0.5).circle_position(&a,&b,&c), CirclePosition::Inside);
+		assert_eq!(p(0.5,0.5).circle_position(&a,&c,&b), CirclePosition::Inside);
+		assert_eq!(p(1.0,1.0).circle_position(&a,&b,&c), CirclePosition::OnCircle);
+		assert_eq!(p(2.0,1.0).circle_position(&c,&b,&a), CirclePosition::Outside);
+		assert_eq!(p(3.0,0.0).circle_position(&a,&b,&p(2.0,0.0)), CirclePosition::OnCircle);
+		assert_eq!(p(3.0,1.0).circle_position(&a,&b,&p(2.0,0.0)), CirclePosition::Outside);
+	}
+
+	#[test]
+    fn circle_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let circle = Circle2D::from_three_points(&p(0.0,0.0),&p(2.0,0.0),&p(0.0,2.0)).unwrap();
+		assert_eq!(circle.center, p(1.0,1.0));
+		assert_eq!(circle.radius, 2.0f64.sqrt());
+		assert_eq!(Circle2D::from_three_points(&p(0.0,0.0),&p(1.0,1.0),&p(2.0,2.0)), None);
+
+		let circle = Circle2D::new(p(0.0,0.0),5.0);
+		assert!(circle.contains_point(&p(3.0,4.0)));
+		assert!(circle.contains_point(&p(1.0,1.0)));
+		assert!(!circle.contains_point(&p(4.0,4.0)));
+
+		let horizontal = InfiniteLine2D::new(p(-10.0,3.0),p(10.0,3.0));
+		assert_eq!(circle.intersection_with_line(&horizontal), vec!(p(-4.0,3.0),p(4.0,3.0)));
+		assert_eq!(circle.intersection_with_line(&InfiniteLine2D::new(p(0.0,5.0),p(1.0,5.0))), vec!(p(0.0,5.0)));
+		assert_eq!(circle.intersection_with_line(&InfiniteLine2D::new(p(0.0,6.0),p(1.0,6.0))), vec!());
+
+		assert_eq!(circle.intersection_with_ray(&Ray2D::new(p(0.0,3.0),p(1.0,3.0))), vec!(p(4.0,3.0)));
+		assert_eq!(circle.intersection_with_ray(&Ray2D::new(p(-10.0,3.0),p(-9.0,3.0))), vec!(p(-4.0,3.0),p(4.0,3.0)));
+		assert_eq!(circle.intersection_with_ray(&Ray2D::new(p(10.0,3.0),p(11.0,3.0))), vec!());
+
+		assert_eq!(circle.intersection_with_segment(&Line2D::new(p(0.0,3.0),p(10.0,3.0))), vec!(p(4.0,3.0)));
+		assert_eq!(circle.intersection_with_segment(&Line2D::new(p(0.0,-3.0),p(1.0,-3.0))), vec!());
+		assert_eq!(circle.intersection_with_segment(&Line2D::new(p(-3.0,-10.0),p(-3.0,10.0))), vec!(p(-3.0,-4.0),p(-3.0,4.0)));
+
+		assert_eq!(circle.intersection_with_circle(&Circle2D::new(p(8.0,0.0),5.0)), vec!(p(4.0,3.0),p(4.0,-3.0)));
+		assert_eq!(circle.intersection_with_circle(&Circle2D::new(p(10.0,0.0),5.0)), vec!(p(5.0,0.0)));
+		assert_eq!(circle.intersection_with_circle(&Circle2D::new(p(2.0,0.0),3.0)), vec!(p(5.0,0.0)));
+		assert_eq!(circle.intersection_with_circle(&Circle2D::new(p(11.0,0.0),5.0)), vec!());
+		assert_eq!(circle.intersection_with_circle(&Circle2D::new(p(1.0,0.0),1.0)), vec!());
+		assert_eq!(circle.intersection_with_circle(&circle), vec!());
+	}
+
+	#[test]
+    fn triangle_test() {
+		use crate::algorithms::polygon_area::{shoelace,signed_shoelace};
+		let p = |x,y| Point2D::new(x,y);
+		let ccw = Triangle2D::new(p(0.0,0.0),p(4.0,0.0),p(0.0,3.0));
+		let cw = Triangle2D::new(p(0.0,0.0),p(0.0,3.0),p(4.0,0.0));
+
+		assert_eq!(ccw.signed_area(), 6.0);
+		assert_eq!(cw.signed_area(), -6.0);
+		assert_eq!(cw.area(), 6.0);
+		assert_eq!(ccw.signed_area(), signed_shoelace(ccw.points().iter()));
+		assert_eq!(cw.signed_area(), signed_shoelace(cw.points().iter()));
+		assert_eq!(cw.area(), shoelace(cw.points().iter()));
+		assert_eq!(ccw.orientation(), TurnDirection::LeftTurn);
+		assert_eq!(cw.orientation(), TurnDirection::RightTurn);
+
+		assert_eq!(ccw.barycentric(&p(0.0,0.0)), Some([1.0,0.0,0.0]));
+		assert_eq!(ccw.barycentric(&p(2.0,0.0)), Some([0.5,0.5,0.0]));
+		assert_eq!(cw.barycentric(&p(0.0,3.0)), Some([0.0,1.0,0.0]));
+		assert_eq!(Triangle2D::new(p(0.0,0.0),p(1.0,1.0),p(2.0,2.0)).barycentric(&p(1.0,0.0)), None);
+
+		for t in [ccw,cw].iter(){
+			assert!(t.contains_point(&p(1.0,1.0)));
+			assert!(t.contains_point_strict(&p(1.0,1.0)));
+			assert!(t.contains_point(&p(2.0,0.0)));
+			assert!(!t.contains_point_strict(&p(2.0,0.0)));
+			assert!(t.contains_point(&p(0.0,3.0)));
+			assert!(!t.contains_point_strict(&p(0.0,3.0)));
+			assert!(!t.contains_point(&p(3.0,3.0)));
+			assert!(!t.contains_point(&p(-1.0,0.0)));
+		}
+		let degenerate = Triangle2D::new(p(0.0,0.0),p(1.0,1.0),p(2.0,2.0));
+		assert!(degenerate.contains_point(&p(1.5,1.5)));
+		assert!(!degenerate.contains_point_strict(&p(1.5,1.5)));
+		assert!(!degenerate.contains_point(&p(1.5,1.0)));
+
+		assert_eq!(Triangle2D::new(p(0.0,0.0),p(3.0,0.0),p(0.0,6.0)).centroid(), p(1.0,2.0));
+		assert_eq!(ccw.circumcenter(), Some(p(2.0,1.5)));
+		assert_eq!(degenerate.circumcenter(), None);
+		assert_eq!(ccw.incenter(), p(1.0,1.0));
+
+		assert_eq!(ccw.longest_edge(), Line2D::new(p(4.0,0.0),p(0.0,3.0)));
+		let right_isosceles = Triangle2D::new(p(0.0,0.0),p(1.0,0.0),p(0.0,1.0));
+		assert!((right_isosceles.min_angle() - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+		assert!((ccw.min_angle() - (3.0f64).atan2(4.0)).abs() < 1e-12);
+		assert_eq!(degenerate.min_angle(), 0.0);
+	}
+
 	#[test]
     fn line_contains_point_test() {
         let line1 = Line2D::new(Point2D::new(0.0,0.0),Point2D::new(1.0,1.0));
@@ -243,5 +1816,187 @@ mod primatives_test {
 		assert!(!line2.contains_point(&Point2D::new(0.5,0.4)));
 		assert!(!line2.contains_point(&Point2D::new(1.5,1.5)));
 
+		let line3 = Line2D::new(Point2D::new(10.0,0.0),Point2D::new(20.0,1.0));
+		assert!(line3.contains_point(&Point2D::new(15.0,0.5)));
+		assert!(!line3.contains_point(&Point2D::new(30.0,2.0)));
+
+	}
+
+	#[test]
+    fn integer_line_test() {
+		let p = |x,y| Point2D::new(x,y);
+		let big = i64::MAX;
+		let diagonal = Line2D::new(p(-big,-big),p(big,big));
+		assert!(diagonal.contains_point(&p(3,3)));
+		assert!(!diagonal.contains_point(&p(3,4)));
+		assert!(!Line2D::new(p(0,0),p(2,2)).contains_point(&p(3,3)));
+
+		assert!(diagonal.intersects_with_line(&Line2D::new(p(big,-big),p(-big,big))));
+		assert!(diagonal.intersects_with_line(&Line2D::new(p(5,5),p(big,0))));
+		assert!(!diagonal.intersects_with_line(&Line2D::new(p(0,1),p(big - 1,big))));
+		assert!(Line2D::new(p(0,0),p(4,0)).intersects_with_line(&Line2D::new(p(4,0),p(9,0))));
+		assert!(!Line2D::new(p(0,0),p(4,0)).intersects_with_line(&Line2D::new(p(5,0),p(9,0))));
+		assert!(Line2D::new(p(0,0),p(9,0)).intersects_with_line(&Line2D::new(p(2,0),p(3,0))));
+	}
+
+	#[test]
+    fn tolerance_test() {
+		let tolerance = Tolerance::default();
+		let line1 = Line2D::new(Point2D::new(0.1,0.3),Point2D::new(7.7,3.9));
+		let line2 = Line2D::new(Point2D::new(0.2,5.3),Point2D::new(6.1,0.7));
+
+		//the rounded crossing point is rarely exactly on either segment
+		let crossing = line1.intersection_point(&line2).unwrap();
+		assert!(line1.contains_point_with_tolerance(&crossing,&tolerance));
+		assert!(line2.contains_point_with_tolerance(&crossing,&tolerance));
+		assert_eq!(crossing.turn_direction_with_tolerance(&line1.p1,&line1.p2,&tolerance),TurnDirection::NoTurn);
+		assert!(!line1.contains_point_with_tolerance(&Point2D::new(crossing.x,crossing.y + 1e-6),&tolerance));
+
+		let p1 = Point2D::new(0.0,0.0);
+		let p2 = Point2D::new(1.0,1.0);
+		let nearly = Point2D::new(2.0,2.0 + 1e-14);
+		assert_eq!(p1.turn_direction(&p2,&nearly),TurnDirection::LeftTurn);
+		assert_eq!(p1.turn_direction_with_tolerance(&p2,&nearly,&tolerance),TurnDirection::NoTurn);
+		assert_eq!(p1.turn_direction_with_tolerance(&p2,&Point2D::new(2.0,2.1),&tolerance),TurnDirection::LeftTurn);
+
+		//segments ending just short of each other
+		let line3 = Line2D::new(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0));
+		let line4 = Line2D::new(Point2D::new(0.5,1e-14),Point2D::new(0.5,1.0));
+		assert_eq!(line3.intersection(&line4),SegmentIntersection::None);
+		assert!(!line3.intersects_with_line(&line4));
+		assert_eq!(line3.intersection_with_tolerance(&line4,&tolerance),SegmentIntersection::Point(line4.p1));
+		assert!(line3.intersects_with_line_with_tolerance(&line4,&tolerance));
+		assert_eq!(line3.intersection_point_with_tolerance(&line4,&tolerance),Some(line4.p1));
+
+		let line5 = Line2D::new(Point2D::new(1.0 + 1e-14,0.0),Point2D::new(2.0,0.0));
+		assert_eq!(line3.intersection(&line5),SegmentIntersection::None);
+		assert!(line3.intersection_with_tolerance(&line5,&tolerance).approx_eq(&SegmentIntersection::Point(line3.p2),&tolerance));
+	}
+
+	#[test]
+    fn approx_eq_test() {
+		let tolerance = Tolerance::default();
+		let p = |x,y| Point2D::new(x,y);
+
+		assert!(p(0.1 + 0.2,1.0).approx_eq(&p(0.3,1.0),&tolerance));
+		assert!(!p(0.3,1.0).approx_eq(&p(0.3,1.001),&tolerance));
+		assert!(Vector2D::new(0.1 + 0.2,0.0).approx_eq(&Vector2D::new(0.3,0.0),&tolerance));
+		assert!(Line2D::new(p(0.0,0.0),p(0.1 + 0.2,1.0)).approx_eq(&Line2D::new(p(0.3,1.0),p(0.0,0.0)),&tolerance));
+		assert!(InfiniteLine2D::new(p(0.0,0.0),p(1.0,1.0)).approx_eq(&InfiniteLine2D::new(p(3.0,3.0),p(-2.0,-2.0 + 1e-15)),&tolerance));
+		assert!(!Ray2D::new(p(0.0,0.0),p(1.0,1.0)).approx_eq(&Ray2D::new(p(0.0,0.0),p(-1.0,-1.0)),&tolerance));
+		assert!(Rect2D::new(p(0.0,0.0),p(0.1 + 0.2,1.0)).approx_eq(&Rect2D::new(p(0.0,0.0),p(0.3,1.0)),&tolerance));
+		assert!(Circle2D::new(p(0.0,0.0),0.1 + 0.2).approx_eq(&Circle2D::new(p(0.0,0.0),0.3),&tolerance));
+		assert!(Triangle2D::new(p(0.0,0.0),p(1.0,0.0),p(0.0,0.1 + 0.2)).approx_eq(&Triangle2D::new(p(0.0,0.0),p(1.0,0.0),p(0.0,0.3)),&tolerance));
+		assert!(!LinearIntersection::Point(p(0.0,0.0)).approx_eq(&LinearIntersection::None,&tolerance));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+    fn serde_test() {
+		let point = Point2D::new(1.5,-2.0);
+		let json = serde_json::to_string(&point).unwrap();
+		assert_eq!(json,r#"{"x":1.5,"y":-2.0}"#);
+		assert_eq!(serde_json::from_str::<Point2D<f64>>(&json).unwrap(),point);
+
+		let line = Line2D::new(point,Point2D::new(0.0,3.0));
+		let json = serde_json::to_string(&line).unwrap();
+		assert_eq!(serde_json::from_str::<Line2D<f64>>(&json).unwrap(),line);
+
+		let json = serde_json::to_string(&TurnDirection::LeftTurn).unwrap();
+		assert_eq!(serde_json::from_str::<TurnDirection>(&json).unwrap(),TurnDirection::LeftTurn);
+	}
+
+	#[test]
+    fn total_point_test() {
+		use std::collections::HashSet;
+
+		let p = |x,y| TotalPoint2D::new(Point2D::new(x,y));
+		let nan = f64::NAN;
+
+		assert_eq!(p(0.0,1.0),p(-0.0,1.0));
+		assert_eq!(p(nan,1.0),p(-nan,1.0));
+		assert!(p(nan,0.0) > p(f64::INFINITY,0.0));
+		assert!(p(1.0,nan) > p(1.0,2.0));
+		assert!(p(1.0,5.0) < p(2.0,0.0));
+		assert!(p(-1.0,0.0) < p(-0.0,0.0));
+
+		let set : HashSet<_> = vec!(p(0.0,0.0),p(-0.0,0.0),p(nan,nan),p(nan,nan),p(1.0,2.0),p(2.0,1.0)).into_iter().collect();
+		assert_eq!(set.len(),4);
+		assert!(set.contains(&p(-0.0,-0.0)));
+
+		let mut points = vec!(p(nan,0.0),p(2.0,0.0),p(-1.0,3.0),p(-1.0,nan));
+		points.sort();
+		assert_eq!(points.iter().map(|p| p.point().x).take(3).collect::<Vec<_>>(),vec!(-1.0,-1.0,2.0));
+		assert!(points[1].point().y.is_nan());
+		assert!(points[3].point().x.is_nan());
+	}
+
+	#[test]
+    fn angle_test() {
+		use std::f64::consts::PI;
+
+		let x = Vector2D::new(1.0,0.0);
+		let y = Vector2D::new(0.0,2.0);
+		assert_eq!(x.angle().radians(),0.0);
+		assert_eq!(y.angle().radians(),PI / 2.0);
+		assert_eq!(Vector2D::new(-1.0,0.0).angle().radians(),PI);
+		assert_eq!(x.signed_angle_to(&y).radians(),PI / 2.0);
+		assert_eq!(y.signed_angle_to(&x).radians(),-PI / 2.0);
+		assert_eq!(y.angle_to(&x).radians(),PI / 2.0);
+		assert_eq!(x.angle_to(&Vector2D::new(-3.0,0.0)).radians(),PI);
+
+		let origin = Point2D::new(1.0,1.0);
+		let (radius,angle) = Point2D::new(1.0,-1.0).to_polar(&origin);
+		assert_eq!(radius,2.0);
+		assert_eq!(angle.radians(),-PI / 2.0);
+		assert!(Point2D::from_polar(&origin,radius,angle).approx_eq(&Point2D::new(1.0,-1.0),&Tolerance::default()));
+		assert!(Point2D::from_polar(&origin,2.0f64.sqrt(),Angle::from_degrees(225.0)).approx_eq(&Point2D::new(0.0,0.0),&Tolerance::default()));
+	}
+
+	#[test]
+    fn pseudo_angle_cmp_test() {
+		let pivot = Point2D::new(0i64,0);
+		//counter-clockwise from the positive x axis, including points on the axes
+		let expected = vec!(
+			Point2D::new(0,0),
+			Point2D::new(2,0),
+			Point2D::new(3,1),
+			Point2D::new(0,5),
+			Point2D::new(-1,1),
+			Point2D::new(-4,0),
+			Point2D::new(-1,-1),
+			Point2D::new(0,-1),
+			Point2D::new(3,-1),
+		);
+		let mut points = expected.clone();
+		points.reverse();
+		points.sort_by(|a,b| a.pseudo_angle_cmp(b,&pivot));
+		assert_eq!(points,expected);
+
+		assert_eq!(Point2D::new(1,1).pseudo_angle_cmp(&Point2D::new(3,3),&pivot),Ordering::Equal);
+		assert_eq!(Point2D::new(1,1).pseudo_angle_cmp(&Point2D::new(-3,-3),&pivot),Ordering::Less);
+
+		//starting from straight down instead
+		let reference = Point2D::new(0,-10);
+		let mut points = expected.clone();
+		points.sort_by(|a,b| a.angle_cmp_from(b,&pivot,&reference));
+		assert_eq!(points,vec!(
+			Point2D::new(0,0),
+			Point2D::new(0,-1),
+			Point2D::new(3,-1),
+			Point2D::new(2,0),
+			Point2D::new(3,1),
+			Point2D::new(0,5),
+			Point2D::new(-1,1),
+			Point2D::new(-4,0),
+			Point2D::new(-1,-1),
+		));
+
+		//pivot away from the origin with nearly collinear float points
+		let pivot = Point2D::new(0.5,0.5);
+		let a = Point2D::new(12.0,12.0);
+		let b = Point2D::new(24.0,24.0 + 1e-14);
+		assert_eq!(a.pseudo_angle_cmp(&b,&pivot),Ordering::Less);
+		assert_eq!(b.pseudo_angle_cmp(&a,&pivot),Ordering::Greater);
 	}
 }
@@ -0,0 +1,500 @@
+
+use std::cmp::Ordering;
+use std::ops::{Add,Sub,Mul,Neg};
+use crate::predicates::orient3d;
+use crate::coordinate::{Coordinate,FloatCoordinate};
+use crate::tolerance::{Tolerance,ApproxEq};
+
+///3D Point
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3D<T: Coordinate>{
+	///Point's x position
+	pub x : T,
+	///Point's y position
+	pub y : T,
+	///Point's z position
+	pub z : T,
+}
+
+impl<T: Coordinate> Point3D<T >{
+
+	///Creates a Point.
+	pub fn new(x:T,y:T,z:T) -> Self{
+		Point3D{x,y,z}
+	}
+
+	///Returns true if self, p1 and p2 lie on a common line, using exact predicates.
+	pub fn is_collinear(&self, p1: &Point3D<T>,p2: &Point3D<T>) -> bool{
+		T::orient2d(&self.x, &self.y, &p1.x, &p1.y, &p2.x, &p2.y) == Ordering::Equal &&
+			T::orient2d(&self.y, &self.z, &p1.y, &p1.z, &p2.y, &p2.z) == Ordering::Equal &&
+			T::orient2d(&self.x, &self.z, &p1.x, &p1.z, &p2.x, &p2.z) == Ordering::Equal
+	}
+
+	///Partial comparision function ordered by x value then by y then by z in case of ties.
+	pub fn x_then_y_then_z_partial_cmp(&self,other: &Point3D<T>) -> Option<Ordering> {
+		if self.x != other.x{
+			self.x.partial_cmp(&other.x)
+		}
+		else if self.y != other.y{
+			self.y.partial_cmp(&other.y)
+		}
+		else{
+			self.z.partial_cmp(&other.z)
+		}
+	}
+
+	///Comparision function ordered by x value then by y then by z in case of ties.
+	pub fn x_then_y_then_z_cmp(&self,other: &Point3D<T>) -> Ordering {
+		if let Some(ord)= self.x_then_y_then_z_partial_cmp(other) {
+			ord
+		}
+		else{
+			Ordering::Greater
+		}
+	}
+}
+
+impl<T: FloatCoordinate> Point3D<T >{
+
+	///Returns the side of the plane through (p1,p2,p3) self is on.
+	///Above is the side from which p1, p2 and p3 appear counter-clockwise.
+	///Uses the exact orient3d predicate, so nearly coplanar points are classified correctly.
+	pub fn plane_side(&self, p1: &Point3D<T>,p2: &Point3D<T>,p3: &Point3D<T>) -> PlaneSide{
+		let det = orient3d(p1.x, p1.y, p1.z, p2.x, p2.y, p2.z, p3.x, p3.y, p3.z, self.x, self.y, self.z);
+
+		if det < T::zero()
+		{
+			PlaneSide::Above
+		}
+		else if det > T::zero()
+		{
+			PlaneSide::Below
+		}
+		else{
+			PlaneSide::OnPlane
+		}
+	}
+
+	///Returns the squared euclidean distance to 'other'.
+	pub fn distance_squared(&self, other: &Point3D<T>) -> T{
+		(*other - *self).norm_squared()
+	}
+
+	///Returns the euclidean distance to 'other'.
+	pub fn distance(&self, other: &Point3D<T>) -> T{
+		(*other - *self).norm()
+	}
+}
+
+///3D Vector
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3D<T: Coordinate>{
+	///Vector's x component
+	pub x : T,
+	///Vector's y component
+	pub y : T,
+	///Vector's z component
+	pub z : T,
+}
+
+impl<T: Coordinate> Vector3D<T >{
+
+	///Creates a Vector.
+	pub fn new(x:T,y:T,z:T) -> Self{
+		Vector3D{x,y,z}
+	}
+
+	///Returns the dot product of the vectors.
+	pub fn dot(&self, other: &Vector3D<T>) -> T{
+		self.x.clone() * other.x.clone() + self.y.clone() * other.y.clone() + self.z.clone() * other.z.clone()
+	}
+
+	///Returns the cross product of the vectors.
+	pub fn cross(&self, other: &Vector3D<T>) -> Vector3D<T>{
+		Vector3D::new(
+			self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+			self.z.clone() * other.x.clone() - self.x.clone() * other.z.clone(),
+			self.x.clone() * other.y.clone() - self.y.clone() * other.x.clone())
+	}
+
+	///Returns the squared length of the vector.
+	pub fn norm_squared(&self) -> T{
+		self.dot(self)
+	}
+}
+
+impl<T: FloatCoordinate> Vector3D<T >{
+
+	///Returns the length of the vector.
+	pub fn norm(&self) -> T{
+		self.norm_squared().sqrt()
+	}
+
+	///Returns the vector scaled to unit length, or None for the zero vector.
+	pub fn normalize(&self) -> Option<Vector3D<T>>{
+		let norm = self.norm();
+		if norm.is_zero(){
+			None
+		}
+		else{
+			Some(Vector3D::new(self.x / norm, self.y / norm, self.z / norm))
+		}
+	}
+}
+
+impl<T: Coordinate> Add for Vector3D<T> {
+	type Output = Vector3D<T>;
+	fn add(self, other: Vector3D<T>) -> Vector3D<T> {
+		Vector3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+	}
+}
+
+impl<T: Coordinate> Sub for Vector3D<T> {
+	type Output = Vector3D<T>;
+	fn sub(self, other: Vector3D<T>) -> Vector3D<T> {
+		Vector3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+	}
+}
+
+impl<T: Coordinate> Mul<T> for Vector3D<T> {
+	type Output = Vector3D<T>;
+	fn mul(self, scalar: T) -> Vector3D<T> {
+		Vector3D::new(self.x * scalar.clone(), self.y * scalar.clone(), self.z * scalar)
+	}
+}
+
+impl<T: Coordinate> Neg for Vector3D<T> {
+	type Output = Vector3D<T>;
+	fn neg(self) -> Vector3D<T> {
+		Vector3D::new(-self.x, -self.y, -self.z)
+	}
+}
+
+impl<T: Coordinate> Sub for Point3D<T> {
+	type Output = Vector3D<T>;
+	fn sub(self, other: Point3D<T>) -> Vector3D<T> {
+		Vector3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+	}
+}
+
+impl<T: Coordinate> Add<Vector3D<T>> for Point3D<T> {
+	type Output = Point3D<T>;
+	fn add(self, vector: Vector3D<T>) -> Point3D<T> {
+		Point3D::new(self.x + vector.x, self.y + vector.y, self.z + vector.z)
+	}
+}
+
+impl<T: Coordinate> Sub<Vector3D<T>> for Point3D<T> {
+	type Output = Point3D<T>;
+	fn sub(self, vector: Vector3D<T>) -> Point3D<T> {
+		Point3D::new(self.x - vector.x, self.y - vector.y, self.z - vector.z)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Point3D<T> {
+	fn approx_eq(&self, other: &Point3D<T>, tolerance: &Tolerance<T>) -> bool {
+		tolerance.eq(self.x,other.x) && tolerance.eq(self.y,other.y) && tolerance.eq(self.z,other.z)
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Vector3D<T> {
+	fn approx_eq(&self, other: &Vector3D<T>, tolerance: &Tolerance<T>) -> bool {
+		tolerance.eq(self.x,other.x) && tolerance.eq(self.y,other.y) && tolerance.eq(self.z,other.z)
+	}
+}
+
+///3D Line
+#[derive(Copy,Clone,Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line3D<T: FloatCoordinate>{
+	///Point 1
+	pub p1 : Point3D<T>,
+	///Point 2
+	pub p2 : Point3D<T>,
+}
+
+impl<T: FloatCoordinate> Line3D<T >{
+	///Creates a 3D Line from p1 to p2.
+	pub fn new(p1:Point3D<T>,p2:Point3D<T>) -> Self{
+		Line3D{p1,p2}
+	}
+
+	///Returns the vector from p1 to p2.
+	pub fn direction(&self) -> Vector3D<T>{
+		self.p2 - self.p1
+	}
+
+	///Returns the length of the line.
+	pub fn length(&self) -> T{
+		self.p1.distance(&self.p2)
+	}
+
+	///returns true if 'point' is on this line
+	pub fn contains_point(&self, point: &Point3D<T>) -> bool{
+		if !self.p1.is_collinear(&self.p2,point){
+			return false;
+		}
+		let within = |value: T,a: T,b: T| value >= a.min(b) && value <= a.max(b);
+		within(point.x,self.p1.x,self.p2.x) &&
+			within(point.y,self.p1.y,self.p2.y) &&
+			within(point.z,self.p1.z,self.p2.z)
+	}
+
+	///Returns the parameter t of the projection of 'point' onto the line through p1 and p2,
+	///where t = 0 is p1 and t = 1 is p2. Returns 0 for a zero length line.
+	pub fn projection_parameter(&self, point: &Point3D<T>) -> T{
+		let direction = self.direction();
+		let length_squared = direction.norm_squared();
+		if length_squared.is_zero(){
+			T::zero()
+		}
+		else{
+			(*point - self.p1).dot(&direction) / length_squared
+		}
+	}
+
+	///Returns the point on the line closest to 'point'.
+	pub fn closest_point(&self, point: &Point3D<T>) -> Point3D<T>{
+		let t = self.projection_parameter(point).max(T::zero()).min(T::one());
+		self.p1 + self.direction() * t
+	}
+
+	///Returns the distance from 'point' to the closest point on the line.
+	pub fn distance_to_point(&self, point: &Point3D<T>) -> T{
+		point.distance(&self.closest_point(point))
+	}
+}
+
+impl<T: FloatCoordinate> PartialEq for Line3D<T> {
+    fn eq(&self, other: &Line3D<T>) -> bool {
+		(self.p1 == other.p1 && self.p2 == other.p2)|| (self.p2 == other.p1 && self.p1 == other.p2)
+    }
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Line3D<T> {
+	fn approx_eq(&self, other: &Line3D<T>, tolerance: &Tolerance<T>) -> bool {
+		(self.p1.approx_eq(&other.p1,tolerance) && self.p2.approx_eq(&other.p2,tolerance)) ||
+			(self.p2.approx_eq(&other.p1,tolerance) && self.p1.approx_eq(&other.p2,tolerance))
+	}
+}
+
+///3D Plane passing through three points.
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane3D<T: FloatCoordinate>{
+	///Point 1
+	pub p1 : Point3D<T>,
+	///Point 2
+	pub p2 : Point3D<T>,
+	///Point 3, must not be collinear with p1 and p2
+	pub p3 : Point3D<T>,
+}
+
+impl<T: FloatCoordinate> Plane3D<T >{
+	///Creates the plane through three points, or None if they are collinear.
+	pub fn from_three_points(p1:Point3D<T>,p2:Point3D<T>,p3:Point3D<T>) -> Option<Self>{
+		if p1.is_collinear(&p2,&p3){
+			None
+		}
+		else{
+			Some(Plane3D{p1,p2,p3})
+		}
+	}
+
+	///Creates the plane through point with the given normal, or None for a zero normal.
+	pub fn from_point_normal(point:Point3D<T>,normal:Vector3D<T>) -> Option<Self>{
+		if normal.norm_squared().is_zero(){
+			return None;
+		}
+		//Cross with the axis least aligned with the normal to get a perpendicular vector.
+		let axis = if normal.x.abs() <= normal.y.abs() && normal.x.abs() <= normal.z.abs(){
+			Vector3D::new(T::one(),T::zero(),T::zero())
+		}
+		else if normal.y.abs() <= normal.z.abs(){
+			Vector3D::new(T::zero(),T::one(),T::zero())
+		}
+		else{
+			Vector3D::new(T::zero(),T::zero(),T::one())
+		};
+		let u = normal.cross(&axis);
+		let v = normal.cross(&u);
+		Some(Plane3D{p1: point,p2: point + u,p3: point + v})
+	}
+
+	///Returns the normal of the plane, pointing to the side from which p1, p2 and p3 appear counter-clockwise.
+	pub fn normal(&self) -> Vector3D<T>{
+		(self.p2 - self.p1).cross(&(self.p3 - self.p1))
+	}
+
+	///Returns which side of the plane 'point' is on.
+	pub fn side(&self, point: &Point3D<T>) -> PlaneSide{
+		point.plane_side(&self.p1,&self.p2,&self.p3)
+	}
+
+	///Returns the signed distance from the plane to 'point', positive above the plane.
+	pub fn signed_distance(&self, point: &Point3D<T>) -> T{
+		let normal = self.normal();
+		(*point - self.p1).dot(&normal) / normal.norm()
+	}
+
+	///Returns the orthogonal projection of 'point' onto the plane.
+	pub fn project_point(&self, point: &Point3D<T>) -> Point3D<T>{
+		let normal = self.normal();
+		let t = (*point - self.p1).dot(&normal) / normal.norm_squared();
+		*point - normal * t
+	}
+
+	///Returns the point where a line segment crosses the plane, or None if it doesn't.
+	///A segment lying in the plane returns its first point.
+	pub fn intersection_with_line(&self, line: &Line3D<T>) -> Option<Point3D<T>>{
+		let side1 = self.side(&line.p1);
+		let side2 = self.side(&line.p2);
+
+		if side1 == PlaneSide::OnPlane{
+			Some(line.p1)
+		}
+		else if side2 == PlaneSide::OnPlane{
+			Some(line.p2)
+		}
+		else if side1 == side2{
+			None
+		}
+		else{
+			let normal = self.normal();
+			let d1 = (line.p1 - self.p1).dot(&normal);
+			let d2 = (line.p2 - self.p1).dot(&normal);
+			let t = (d1 / (d1 - d2)).max(T::zero()).min(T::one());
+			Some(line.p1 + line.direction() * t)
+		}
+	}
+}
+
+impl<T: FloatCoordinate> ApproxEq<T> for Plane3D<T> {
+	fn approx_eq(&self, other: &Plane3D<T>, tolerance: &Tolerance<T>) -> bool {
+		self.p1.approx_eq(&other.p1,tolerance) && self.p2.approx_eq(&other.p2,tolerance) && self.p3.approx_eq(&other.p3,tolerance)
+	}
+}
+
+///Enum representing the position of a point relative to a plane.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlaneSide{
+	///On the side the plane's normal points to.
+	Above,
+	///On the side opposite to the plane's normal.
+	Below,
+	///On the plane.
+	OnPlane,
+}
+
+#[cfg(test)]
+mod primatives_test {
+	use super::*;
+
+    #[test]
+    fn point_new_test() {
+        let p =Point3D::new(2.3,6.7,1.5);
+		assert_eq!(p.x, 2.3);
+		assert_eq!(p.y, 6.7);
+		assert_eq!(p.z, 1.5);
+	}
+
+	#[test]
+    fn plane_side_test() {
+		let a = Point3D::new(0.0,0.0,0.0);
+		let b = Point3D::new(1.0,0.0,0.0);
+		let c = Point3D::new(0.0,1.0,0.0);
+		assert_eq!(Point3D::new(0.2,0.2,1.0).plane_side(&a,&b,&c), PlaneSide::Above);
+		assert_eq!(Point3D::new(0.2,0.2,-1.0).plane_side(&a,&b,&c), PlaneSide::Below);
+		assert_eq!(Point3D::new(0.2,0.2,1.0).plane_side(&a,&c,&b), PlaneSide::Below);
+		assert_eq!(Point3D::new(5.0,-3.0,0.0).plane_side(&a,&b,&c), PlaneSide::OnPlane);
+
+		let step = 2.0f64.powi(-53);
+		let p = Point3D::new(1.5,0.0,0.0);
+		let q = Point3D::new(0.0,1.5,0.0);
+		let r = Point3D::new(0.0,0.0,1.5);
+		for i in 1..16{
+			assert_eq!(Point3D::new(0.5,0.5,0.5 + (i as f64)*step).plane_side(&p,&q,&r), PlaneSide::Above);
+			assert_eq!(Point3D::new(0.5,0.5,0.5 - (i as f64)*step).plane_side(&p,&q,&r), PlaneSide::Below);
+		}
+		assert_eq!(Point3D::new(0.5,0.5,0.5).plane_side(&p,&q,&r), PlaneSide::OnPlane);
+	}
+
+	#[test]
+    fn comparison_test() {
+		let p = Point3D::new(1.0,2.0,3.0);
+		assert_eq!(p.x_then_y_then_z_cmp(&Point3D::new(2.0,0.0,0.0)), Ordering::Less);
+		assert_eq!(p.x_then_y_then_z_cmp(&Point3D::new(1.0,1.0,9.0)), Ordering::Greater);
+		assert_eq!(p.x_then_y_then_z_cmp(&Point3D::new(1.0,2.0,4.0)), Ordering::Less);
+		assert_eq!(p.x_then_y_then_z_cmp(&p), Ordering::Equal);
+		assert_eq!(p.x_then_y_then_z_partial_cmp(&Point3D::new(1.0,2.0,f64::NAN)), None);
+	}
+
+	#[test]
+    fn vector_test() {
+		let v = Vector3D::new(1.0,0.0,0.0);
+		let w = Vector3D::new(0.0,1.0,0.0);
+		assert_eq!(v.cross(&w), Vector3D::new(0.0,0.0,1.0));
+		assert_eq!(v.dot(&w), 0.0);
+		assert_eq!(Vector3D::new(2.0,3.0,6.0).norm(), 7.0);
+		assert_eq!(Vector3D::new(0.0,0.0,2.0).normalize(), Some(Vector3D::new(0.0,0.0,1.0)));
+		assert_eq!(v + w - v * 2.0, Vector3D::new(-1.0,1.0,0.0));
+		assert_eq!(-v, Vector3D::new(-1.0,0.0,0.0));
+		assert_eq!(Point3D::new(1.0,1.0,1.0) - Point3D::new(0.0,1.0,2.0), Vector3D::new(1.0,0.0,-1.0));
+		assert_eq!(Point3D::new(1.0,1.0,1.0) + v, Point3D::new(2.0,1.0,1.0));
+
+		let v = Point3D::new(3i64,1,2) - Point3D::new(1,1,1);
+		assert_eq!(v, Vector3D::new(2,0,1));
+		assert_eq!(v.cross(&Vector3D::new(0,1,0)), Vector3D::new(-1,0,2));
+		assert_eq!(v.norm_squared(), 5);
+		assert_eq!(Point3D::new(1,1,1) + v * 2 - -v, Point3D::new(7,1,4));
+		assert!(Point3D::new(0i64,0,0).is_collinear(&Point3D::new(1,2,3),&Point3D::new(i64::MAX / 4,2 * (i64::MAX / 4),3 * (i64::MAX / 4))));
+		assert!(!Point3D::new(0i64,0,0).is_collinear(&Point3D::new(1,2,3),&Point3D::new(2,4,7)));
+	}
+
+	#[test]
+    fn line_test() {
+		let line = Line3D::new(Point3D::new(0.0,0.0,0.0),Point3D::new(2.0,2.0,2.0));
+		assert!(line.contains_point(&Point3D::new(1.0,1.0,1.0)));
+		assert!(!line.contains_point(&Point3D::new(1.0,1.0,1.5)));
+		assert!(!line.contains_point(&Point3D::new(3.0,3.0,3.0)));
+		assert_eq!(line.closest_point(&Point3D::new(1.0,1.0,4.0)), Point3D::new(2.0,2.0,2.0));
+		assert_eq!(line.closest_point(&Point3D::new(-1.0,-1.0,-1.0)), Point3D::new(0.0,0.0,0.0));
+		assert_eq!(Line3D::new(Point3D::new(0.0,0.0,0.0),Point3D::new(0.0,0.0,4.0)).distance_to_point(&Point3D::new(3.0,4.0,2.0)), 5.0);
+		assert_eq!(line, Line3D::new(Point3D::new(2.0,2.0,2.0),Point3D::new(0.0,0.0,0.0)));
+	}
+
+	#[test]
+    fn plane_test() {
+		let plane = Plane3D::from_point_normal(Point3D::new(0.0,0.0,1.0),Vector3D::new(0.0,0.0,2.0)).unwrap();
+		assert_eq!(plane.side(&Point3D::new(5.0,5.0,3.0)), PlaneSide::Above);
+		assert_eq!(plane.side(&Point3D::new(5.0,5.0,-3.0)), PlaneSide::Below);
+		assert_eq!(plane.side(&Point3D::new(5.0,5.0,1.0)), PlaneSide::OnPlane);
+		assert_eq!(plane.signed_distance(&Point3D::new(5.0,5.0,3.0)), 2.0);
+		assert_eq!(plane.project_point(&Point3D::new(5.0,4.0,3.0)), Point3D::new(5.0,4.0,1.0));
+		assert_eq!(Plane3D::from_point_normal(Point3D::new(0.0,0.0,1.0),Vector3D::new(0.0,0.0,0.0)), None);
+
+		let line = Line3D::new(Point3D::new(1.0,2.0,0.0),Point3D::new(1.0,2.0,4.0));
+		assert_eq!(plane.intersection_with_line(&line), Some(Point3D::new(1.0,2.0,1.0)));
+		let above = Line3D::new(Point3D::new(1.0,2.0,2.0),Point3D::new(1.0,2.0,4.0));
+		assert_eq!(plane.intersection_with_line(&above), None);
+
+		assert_eq!(Plane3D::from_three_points(Point3D::new(0.0,0.0,0.0),Point3D::new(1.0,1.0,1.0),Point3D::new(2.0,2.0,2.0)), None);
+		let tilted = Plane3D::from_three_points(Point3D::new(1.0,0.0,0.0),Point3D::new(0.0,1.0,0.0),Point3D::new(0.0,0.0,1.0)).unwrap();
+		assert_eq!(tilted.side(&Point3D::new(1.0,1.0,1.0)), PlaneSide::Above);
+		assert_eq!(tilted.side(&Point3D::new(0.0,0.0,0.0)), PlaneSide::Below);
+	}
+
+	#[test]
+    fn approx_eq_test() {
+		let tolerance = Tolerance::default();
+		let p = |x,y,z| Point3D::new(x,y,z);
+
+		assert!(p(0.1 + 0.2,0.0,1.0).approx_eq(&p(0.3,0.0,1.0),&tolerance));
+		assert!(!p(0.3,0.0,1.0).approx_eq(&p(0.3,0.0,1.001),&tolerance));
+		assert!(Vector3D::new(0.0,0.1 + 0.2,0.0).approx_eq(&Vector3D::new(0.0,0.3,0.0),&tolerance));
+		assert!(Line3D::new(p(0.0,0.0,0.0),p(0.1 + 0.2,0.0,0.0)).approx_eq(&Line3D::new(p(0.3,0.0,0.0),p(0.0,0.0,0.0)),&tolerance));
+	}
+}
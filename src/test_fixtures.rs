@@ -0,0 +1,15 @@
+//!Shapes shared by the tests of several modules.
+
+use crate::primatives2d::Point2D;
+use crate::polygon2d::Polygon2D;
+
+
+///Axis aligned rectangle polygon with corners (x1,y1) and (x2,y2), going x first.
+pub(crate) fn rect(x1: f64,y1: f64,x2: f64,y2: f64) -> Polygon2D<f64>{
+	Polygon2D::new(vec!(
+		Point2D::new(x1,y1),
+		Point2D::new(x2,y1),
+		Point2D::new(x2,y2),
+		Point2D::new(x1,y2)
+	)).unwrap()
+}
@@ -0,0 +1,101 @@
+//!Tolerances for approximate geometric comparisons.
+//The exact predicates answer questions about the coordinates as given, computed points such as
+//intersections are rounded, so checking them against the objects they came from needs a tolerance.
+
+use num_traits::Float;
+
+///Absolute and relative epsilon used by approximate comparisons.
+///Two values a and b are considered equal if |a - b| <= max(absolute, relative * max(|a|,|b|)).
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerance<T: Float>{
+	///Smallest difference that is always ignored, useful near zero
+	pub absolute : T,
+	///Difference ignored relative to the magnitude of the compared values
+	pub relative : T,
+}
+
+impl<T: Float> Tolerance<T >{
+	///Creates a tolerance from an absolute and a relative epsilon.
+	pub fn new(absolute:T,relative:T) -> Self{
+		Tolerance{absolute,relative}
+	}
+
+	///Creates a tolerance that only uses an absolute epsilon.
+	pub fn absolute(absolute:T) -> Self{
+		Tolerance{absolute,relative: T::zero()}
+	}
+
+	///Creates a tolerance that only uses a relative epsilon.
+	pub fn relative(relative:T) -> Self{
+		Tolerance{absolute: T::zero(),relative}
+	}
+
+	///Returns true if a and b are equal within the tolerance.
+	pub fn eq(&self, a:T, b:T) -> bool{
+		self.is_zero(a - b, a.abs().max(b.abs()))
+	}
+
+	///Returns true if 'value' is negligible for a computation on values of magnitude 'scale'.
+	pub fn is_zero(&self, value:T, scale:T) -> bool{
+		value.abs() <= self.absolute.max(self.relative * scale.abs())
+	}
+}
+
+impl<T: Float> Default for Tolerance<T>{
+	///1024 machine epsilons, both absolute and relative.
+	///Enough to absorb the rounding of a few chained operations.
+	fn default() -> Self{
+		let epsilon = T::epsilon() * T::from(1024).unwrap();
+		Tolerance::new(epsilon,epsilon)
+	}
+}
+
+///Equality within a tolerance.
+pub trait ApproxEq<T: Float>{
+	///Returns true if self and other are equal within 'tolerance'.
+	fn approx_eq(&self, other: &Self, tolerance: &Tolerance<T>) -> bool;
+}
+
+macro_rules! float_approx_eq {
+	($($t:ty),*) => {$(
+		impl ApproxEq<$t> for $t {
+			fn approx_eq(&self, other: &Self, tolerance: &Tolerance<$t>) -> bool{
+				tolerance.eq(*self,*other)
+			}
+		}
+	)*};
+}
+
+float_approx_eq!(f32, f64);
+
+impl<T: Float, A: ApproxEq<T>> ApproxEq<T> for [A] {
+	///Slices are equal if they have the same length and are equal element by element.
+	fn approx_eq(&self, other: &Self, tolerance: &Tolerance<T>) -> bool{
+		self.len() == other.len() && self.iter().zip(other.iter()).all(|(a,b)| a.approx_eq(b,tolerance))
+	}
+}
+
+
+#[cfg(test)]
+mod tolerance_test {
+	use super::*;
+
+	#[test]
+	fn eq_test() {
+		let tolerance = Tolerance::new(1e-9,1e-6);
+		assert!(tolerance.eq(0.0,1e-10));
+		assert!(!tolerance.eq(0.0,1e-8));
+		assert!(tolerance.eq(1e6,1e6 + 0.5));
+		assert!(!tolerance.eq(1e6,1e6 + 2.0));
+		assert!(Tolerance::absolute(0.1).eq(1e6,1e6 + 0.05));
+		assert!(!Tolerance::relative(0.1).eq(0.0,1e-300));
+
+		let tolerance = Tolerance::default();
+		assert!((0.1f64 + 0.2).approx_eq(&0.3,&tolerance));
+		assert!(!0.3f64.approx_eq(&0.30001,&tolerance));
+
+		assert!([0.1f64 + 0.2,1.0][..].approx_eq(&[0.3,1.0][..],&tolerance));
+		assert!(![0.3f64,1.0][..].approx_eq(&[0.3][..],&tolerance));
+	}
+}
@@ -0,0 +1,217 @@
+//!Affine transformations of 2D geometry.
+
+use crate::primatives2d::{Point2D,Vector2D,Line2D};
+use crate::coordinate::FloatCoordinate;
+use std::ops::Mul;
+
+///2D Affine transformation stored as a 3x3 matrix acting on column vectors (x,y,1).
+///The last row is always (0,0,1).
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D<T: FloatCoordinate>{
+	///Row major matrix
+	pub matrix : [[T;3];3],
+}
+
+impl<T: FloatCoordinate> Transform2D<T >{
+	///Creates the affine transformation x' = a*x + b*y + c, y' = d*x + e*y + f.
+	pub fn new(a:T,b:T,c:T,d:T,e:T,f:T) -> Self{
+		Transform2D{matrix: [
+			[a,b,c],
+			[d,e,f],
+			[T::zero(),T::zero(),T::one()],
+		]}
+	}
+
+	///Creates the transformation that leaves everything in place.
+	pub fn identity() -> Self{
+		Transform2D::new(T::one(),T::zero(),T::zero(),T::zero(),T::one(),T::zero())
+	}
+
+	///Creates a translation by 'offset'.
+	pub fn translation(offset: Vector2D<T>) -> Self{
+		Transform2D::new(T::one(),T::zero(),offset.x,T::zero(),T::one(),offset.y)
+	}
+
+	///Creates a counter-clockwise rotation by 'angle' radians around the origin.
+	pub fn rotation(angle: T) -> Self{
+		let (sin,cos) = angle.sin_cos();
+		Transform2D::new(cos,-sin,T::zero(),sin,cos,T::zero())
+	}
+
+	///Creates a counter-clockwise rotation by 'angle' radians around 'center'.
+	pub fn rotation_around(center: &Point2D<T>, angle: T) -> Self{
+		let offset = *center - Point2D::new(T::zero(),T::zero());
+		Transform2D::translation(-offset).then(&Transform2D::rotation(angle)).then(&Transform2D::translation(offset))
+	}
+
+	///Creates a scaling along the axes around the origin. Negative factors mirror.
+	pub fn scale(x:T,y:T) -> Self{
+		Transform2D::new(x,T::zero(),T::zero(),T::zero(),y,T::zero())
+	}
+
+	///Creates a shear, x' = x + x_shear*y and y' = y + y_shear*x.
+	pub fn shear(x_shear:T,y_shear:T) -> Self{
+		Transform2D::new(T::one(),x_shear,T::zero(),y_shear,T::one(),T::zero())
+	}
+
+	///Returns the transformation applying self first and then 'next'.
+	pub fn then(&self, next: &Transform2D<T>) -> Transform2D<T>{
+		*next * *self
+	}
+
+	///Returns the determinant of the linear part, negative if the transformation mirrors.
+	pub fn determinant(&self) -> T{
+		let m = &self.matrix;
+		m[0][0] * m[1][1] - m[0][1] * m[1][0]
+	}
+
+	///Returns the transformation undoing self, or None if self collapses the plane.
+	pub fn inverse(&self) -> Option<Transform2D<T>>{
+		let determinant = self.determinant();
+		if determinant.is_zero() || !determinant.is_finite(){
+			return None;
+		}
+
+		let m = &self.matrix;
+		let a =  m[1][1] / determinant;
+		let b = -m[0][1] / determinant;
+		let d = -m[1][0] / determinant;
+		let e =  m[0][0] / determinant;
+		let c = -(a * m[0][2] + b * m[1][2]);
+		let f = -(d * m[0][2] + e * m[1][2]);
+		Some(Transform2D::new(a,b,c,d,e,f))
+	}
+
+	///Returns the transformed point.
+	pub fn apply_to_point(&self, point: &Point2D<T>) -> Point2D<T>{
+		let m = &self.matrix;
+		Point2D::new(
+			m[0][0] * point.x + m[0][1] * point.y + m[0][2],
+			m[1][0] * point.x + m[1][1] * point.y + m[1][2],
+		)
+	}
+
+	///Returns the transformed vector, translations do not affect vectors.
+	pub fn apply_to_vector(&self, vector: &Vector2D<T>) -> Vector2D<T>{
+		let m = &self.matrix;
+		Vector2D::new(
+			m[0][0] * vector.x + m[0][1] * vector.y,
+			m[1][0] * vector.x + m[1][1] * vector.y,
+		)
+	}
+}
+
+impl<T: FloatCoordinate> Mul for Transform2D<T> {
+	type Output = Transform2D<T>;
+	///Matrix product, the result applies 'other' first and then self.
+	fn mul(self, other: Transform2D<T>) -> Transform2D<T> {
+		let mut matrix = [[T::zero();3];3];
+		for (i,row) in matrix.iter_mut().enumerate(){
+			for (j,value) in row.iter_mut().enumerate(){
+				*value = (0..3).fold(T::zero(),|sum,k| sum + self.matrix[i][k] * other.matrix[k][j]);
+			}
+		}
+		Transform2D{matrix}
+	}
+}
+
+impl<T: FloatCoordinate> Default for Transform2D<T>{
+	fn default() -> Self{
+		Transform2D::identity()
+	}
+}
+
+///Geometry that can be moved by an affine transformation in place.
+pub trait Transform<T: FloatCoordinate>{
+	///Applies 'transform' to every coordinate of self.
+	fn transform(&mut self, transform: &Transform2D<T>);
+}
+
+impl<T: FloatCoordinate> Transform<T> for Point2D<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		*self = transform.apply_to_point(self);
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for Vector2D<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		*self = transform.apply_to_vector(self);
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for Line2D<T> {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		self.p1.transform(transform);
+		self.p2.transform(transform);
+	}
+}
+
+impl<T: FloatCoordinate> Transform<T> for [Point2D<T>] {
+	fn transform(&mut self, transform: &Transform2D<T>){
+		for point in self.iter_mut(){
+			point.transform(transform);
+		}
+	}
+}
+
+#[cfg(test)]
+mod transform_test {
+	use super::*;
+	use crate::tolerance::{Tolerance,ApproxEq};
+	use crate::polygon2d::{Polygon2D,Orientation};
+
+	#[test]
+	fn transform_point_test() {
+		let tolerance = Tolerance::default();
+		let p = Point2D::new(1.0,2.0);
+
+		assert_eq!(Transform2D::identity().apply_to_point(&p),p);
+		assert_eq!(Transform2D::translation(Vector2D::new(3.0,-1.0)).apply_to_point(&p),Point2D::new(4.0,1.0));
+		assert_eq!(Transform2D::scale(2.0,-3.0).apply_to_point(&p),Point2D::new(2.0,-6.0));
+		assert_eq!(Transform2D::shear(1.0,0.0).apply_to_point(&p),Point2D::new(3.0,2.0));
+		assert!(Transform2D::rotation(std::f64::consts::FRAC_PI_2).apply_to_point(&p).approx_eq(&Point2D::new(-2.0,1.0),&tolerance));
+		assert!(Transform2D::rotation_around(&Point2D::new(1.0,1.0),std::f64::consts::PI).apply_to_point(&p)
+			.approx_eq(&Point2D::new(1.0,0.0),&tolerance));
+
+		let v = Vector2D::new(1.0,2.0);
+		assert_eq!(Transform2D::translation(Vector2D::new(3.0,-1.0)).apply_to_vector(&v),v);
+	}
+
+	#[test]
+	fn compose_and_inverse_test() {
+		let tolerance = Tolerance::default();
+		let p = Point2D::new(1.0,2.0);
+		let translate = Transform2D::translation(Vector2D::new(3.0,-1.0));
+		let scale = Transform2D::scale(2.0,2.0);
+
+		assert_eq!(translate.then(&scale).apply_to_point(&p),Point2D::new(8.0,2.0));
+		assert_eq!((translate * scale).apply_to_point(&p),Point2D::new(5.0,3.0));
+
+		let transform = Transform2D::rotation(0.7).then(&Transform2D::shear(0.3,-1.2)).then(&translate);
+		let inverse = transform.inverse().unwrap();
+		assert!(inverse.apply_to_point(&transform.apply_to_point(&p)).approx_eq(&p,&tolerance));
+		assert!(transform.then(&inverse).apply_to_point(&p).approx_eq(&p,&tolerance));
+
+		assert_eq!(Transform2D::scale(1.0,0.0).inverse(),None);
+		assert_eq!(Transform2D::scale(-1.0,1.0).determinant(),-1.0);
+	}
+
+	#[test]
+	fn transform_trait_test() {
+		let transform = Transform2D::translation(Vector2D::new(1.0,1.0));
+
+		let mut line = Line2D::new(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0));
+		line.transform(&transform);
+		assert_eq!(line,Line2D::new(Point2D::new(1.0,1.0),Point2D::new(2.0,1.0)));
+
+		let mut points = vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0));
+		points.transform(&transform);
+		assert_eq!(points,vec!(Point2D::new(1.0,1.0),Point2D::new(2.0,1.0)));
+
+		let mut polygon = Polygon2D::new(vec!(Point2D::new(0.0,0.0),Point2D::new(1.0,0.0),Point2D::new(0.0,1.0))).unwrap();
+		polygon.transform(&Transform2D::scale(-2.0,2.0));
+		assert_eq!(polygon.area(),2.0);
+		assert_eq!(polygon.orientation(),Orientation::Clockwise);
+	}
+}